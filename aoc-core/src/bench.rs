@@ -0,0 +1,97 @@
+use crate::{Error, PuzzleInput, Solution};
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Timings of repeated runs of one phase.
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.1?}", self.median)
+    }
+}
+
+/// Runs `f` once to warm up, then repeatedly until `budget` is spent (at least a handful
+/// of times, so a single slow run still gets a median).
+pub fn measure<T>(budget: Duration, mut f: impl FnMut() -> T) -> Stats {
+    const MIN_ITERATIONS: usize = 5;
+    const MAX_ITERATIONS: usize = 100_000;
+    black_box(f());
+    let mut samples = Vec::new();
+    let started = Instant::now();
+    while samples.len() < MIN_ITERATIONS
+        || (started.elapsed() < budget && samples.len() < MAX_ITERATIONS)
+    {
+        let run = Instant::now();
+        black_box(f());
+        samples.push(run.elapsed());
+    }
+    samples.sort();
+    Stats {
+        iterations: samples.len(),
+        min: samples[0],
+        median: samples[samples.len() / 2],
+        mean: samples.iter().sum::<Duration>() / samples.len() as u32,
+    }
+}
+
+/// Parse and per-part timings for one day. A part that fails on its input is recorded
+/// as the error instead of a timing.
+pub struct Timings {
+    pub day: u8,
+    pub parse: Stats,
+    pub part1: Result<Stats, Error>,
+    pub part2: Result<Stats, Error>,
+}
+
+/// Times parsing of the part 1 input and each part against its own parsed input.
+pub fn bench<S: Solution>(inputs: [&PuzzleInput; 2], budget: Duration) -> Result<Timings, Error> {
+    let parse = measure(budget, || S::parse(black_box(inputs[0])));
+    let first = S::parse(inputs[0])?;
+    let second = S::parse(inputs[1])?;
+    let part1 = S::part1(&first).map(|_| measure(budget, || S::part1(black_box(&first))));
+    let part2 = S::part2(&second).map(|_| measure(budget, || S::part2(black_box(&second))));
+    Ok(Timings {
+        day: S::DAY,
+        parse,
+        part1,
+        part2,
+    })
+}
+
+/// Prints one row per day with the median time of each phase.
+pub fn print_report(timings: &[Timings]) {
+    fn cell(stats: &Result<Stats, Error>) -> String {
+        match stats {
+            Ok(stats) => stats.to_string(),
+            Err(_) => String::from("failed"),
+        }
+    }
+    println!(
+        "{:>3}  {:>12}  {:>12}  {:>12}",
+        "day", "parse", "part 1", "part 2"
+    );
+    for t in timings {
+        println!(
+            "{:>3}  {:>12}  {:>12}  {:>12}",
+            t.day,
+            t.parse.to_string(),
+            cell(&t.part1),
+            cell(&t.part2)
+        );
+    }
+    for t in timings {
+        for (part, stats) in [(1, &t.part1), (2, &t.part2)] {
+            if let Err(err) = stats {
+                eprintln!("day {} part {part} failed: {err}", t.day);
+            }
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// A malformed piece of puzzle input. `line` and `column` are 1-based and `text` is the
+/// fragment of the line that could not be understood.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        text: impl Into<String>,
+        message: impl Into<String>,
+    ) -> ParseError {
+        ParseError {
+            line,
+            column,
+            text: text.into(),
+            message: message.into(),
+        }
+    }
+
+    /// Builds an error for `fragment`, working out its column from where it sits inside
+    /// `line`. Fragments that are not slices of `line` are reported at column 1.
+    pub fn at(
+        line_number: usize,
+        line: &str,
+        fragment: &str,
+        message: impl Into<String>,
+    ) -> ParseError {
+        let line_start = line.as_ptr() as usize;
+        let fragment_start = fragment.as_ptr() as usize;
+        let column = if fragment_start >= line_start
+            && fragment_start + fragment.len() <= line_start + line.len()
+        {
+            fragment_start - line_start + 1
+        } else {
+            1
+        };
+        ParseError::new(line_number, column, fragment, message)
+    }
+
+    /// Builds an error for something missing at the end of `line`.
+    pub fn missing(line_number: usize, line: &str, message: impl Into<String>) -> ParseError {
+        ParseError::new(line_number, line.len() + 1, line, message)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {} (found {:?})",
+            self.line, self.column, self.message, self.text
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `token`, a slice of `line`, as a number.
+pub fn parse_number<T: FromStr>(
+    line_number: usize,
+    line: &str,
+    token: &str,
+) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(line_number, line, token, "expected a number"))
+}
@@ -0,0 +1,250 @@
+use crate::{ParseError, PuzzleInput};
+use std::ops::Range;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Coordinate {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Coordinate {
+    pub fn new(row: usize, col: usize) -> Coordinate {
+        Coordinate { row, col }
+    }
+}
+
+/// A rectangular grid stored row by row. Every accessor is bounds-checked, so callers can
+/// ask about cells off the edge and get `None` (or nothing) back instead of underflowing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from rows of equal length, or `None` if the rows are ragged.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Grid<T>> {
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        let height = rows.len();
+        Some(Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index(&self, at: Coordinate) -> Option<usize> {
+        if at.row < self.height && at.col < self.width {
+            Some(at.row * self.width + at.col)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, at: Coordinate) -> Option<&T> {
+        self.index(at).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, at: Coordinate) -> Option<&mut T> {
+        self.index(at).map(|i| &mut self.cells[i])
+    }
+
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        if row < self.height {
+            Some(&self.cells[row * self.width..(row + 1) * self.width])
+        } else {
+            None
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|row| &self.cells[row * self.width..(row + 1) * self.width])
+    }
+
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> {
+        let rows = if col < self.width { self.height } else { 0 };
+        (0..rows).map(move |row| &self.cells[row * self.width + col])
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = (Coordinate, &T)> {
+        self.region(0..self.height, 0..self.width)
+    }
+
+    /// Cells inside the given block, clipped to the grid.
+    pub fn region(
+        &self,
+        rows: Range<usize>,
+        cols: Range<usize>,
+    ) -> impl Iterator<Item = (Coordinate, &T)> {
+        let rows = rows.start.min(self.height)..rows.end.min(self.height);
+        let cols = cols.start.min(self.width)..cols.end.min(self.width);
+        rows.flat_map(move |row| {
+            cols.clone().map(move |col| {
+                (
+                    Coordinate::new(row, col),
+                    &self.cells[row * self.width + col],
+                )
+            })
+        })
+    }
+
+    /// Cells touching the given block, diagonals included, but not inside it. The ring of
+    /// a single cell is its 8-neighbourhood.
+    pub fn ring(
+        &self,
+        rows: Range<usize>,
+        cols: Range<usize>,
+    ) -> impl Iterator<Item = (Coordinate, &T)> {
+        let outer_rows = rows.start.saturating_sub(1)..rows.end + 1;
+        let outer_cols = cols.start.saturating_sub(1)..cols.end + 1;
+        self.region(outer_rows, outer_cols)
+            .filter(move |(at, _)| !(rows.contains(&at.row) && cols.contains(&at.col)))
+    }
+
+    /// The up to four cells sharing an edge with `at`.
+    pub fn neighbours4(&self, at: Coordinate) -> impl Iterator<Item = (Coordinate, &T)> {
+        self.neighbours8(at)
+            .filter(move |(other, _)| other.row == at.row || other.col == at.col)
+    }
+
+    /// The up to eight cells sharing an edge or a corner with `at`.
+    pub fn neighbours8(&self, at: Coordinate) -> impl Iterator<Item = (Coordinate, &T)> {
+        self.ring(at.row..at.row + 1, at.col..at.col + 1)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn transpose(&self) -> Grid<T> {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: (0..self.width)
+                .flat_map(|col| self.column(col).cloned())
+                .collect(),
+        }
+    }
+
+    pub fn rotate_clockwise(&self) -> Grid<T> {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: (0..self.width)
+                .flat_map(|col| self.column(col).rev().cloned())
+                .collect(),
+        }
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Grid<T> {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: (0..self.width)
+                .rev()
+                .flat_map(|col| self.column(col).cloned())
+                .collect(),
+        }
+    }
+}
+
+impl Grid<u8> {
+    /// Reads a grid of ASCII bytes, one row per line, rejecting ragged rows.
+    pub fn parse(input: &PuzzleInput) -> Result<Grid<u8>, ParseError> {
+        let mut rows: Vec<Vec<u8>> = Vec::new();
+        for line in input.lines() {
+            if let Some((col, c)) = line.text.char_indices().find(|(_, c)| !c.is_ascii()) {
+                let end = col + c.len_utf8();
+                return Err(ParseError::at(
+                    line.number,
+                    line.text,
+                    &line.text[col..end],
+                    "expected an ASCII character",
+                ));
+            }
+            if let Some(first) = rows.first() {
+                if line.text.len() != first.len() {
+                    return Err(ParseError::missing(
+                        line.number,
+                        line.text,
+                        format!("expected a row of width {}", first.len()),
+                    ));
+                }
+            }
+            rows.push(line.text.as_bytes().to_vec());
+        }
+        Ok(Grid::from_rows(rows).unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<u8> {
+        Grid::parse(&"abc\ndef".into()).unwrap()
+    }
+
+    fn text(grid: &Grid<u8>) -> Vec<String> {
+        grid.rows()
+            .map(|row| String::from_utf8(row.to_vec()).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn access_is_bounds_checked() {
+        let grid = sample();
+        assert_eq!(grid.get(Coordinate::new(1, 2)), Some(&b'f'));
+        assert_eq!(grid.get(Coordinate::new(2, 0)), None);
+        assert_eq!(grid.get(Coordinate::new(0, 3)), None);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), b"be");
+        assert_eq!(grid.column(3).count(), 0);
+    }
+
+    #[test]
+    fn neighbours_stop_at_the_edges() {
+        let grid = sample();
+        let corner: Vec<u8> = grid
+            .neighbours8(Coordinate::new(0, 0))
+            .map(|(_, c)| *c)
+            .collect();
+        assert_eq!(corner, b"bde");
+        let middle: Vec<u8> = grid
+            .neighbours4(Coordinate::new(0, 1))
+            .map(|(_, c)| *c)
+            .collect();
+        assert_eq!(middle, b"ace");
+    }
+
+    #[test]
+    fn ring_skips_the_block_itself() {
+        let grid = sample();
+        let ring: Vec<u8> = grid.ring(0..1, 0..2).map(|(_, c)| *c).collect();
+        assert_eq!(ring, b"cdef");
+    }
+
+    #[test]
+    fn transpose_and_rotate() {
+        let grid = sample();
+        assert_eq!(text(&grid.transpose()), ["ad", "be", "cf"]);
+        assert_eq!(text(&grid.rotate_clockwise()), ["da", "eb", "fc"]);
+        assert_eq!(text(&grid.rotate_counterclockwise()), ["cf", "be", "ad"]);
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+    }
+
+    #[test]
+    fn ragged_rows_are_rejected() {
+        let err = Grid::parse(&"abc\nde".into()).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+    }
+}
@@ -0,0 +1,170 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+
+/// One line of puzzle input with its 1-based line number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+/// A block of lines separated from its neighbours by blank lines. When the block's first
+/// line ends in `:` (like `seed-to-soil map:`) it is taken as the header and the rest
+/// as the body; otherwise every line is body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section<'a> {
+    pub header: Option<Line<'a>>,
+    pub body: Vec<Line<'a>>,
+}
+
+impl<'a> Section<'a> {
+    /// The header text without its trailing `:`.
+    pub fn name(&self) -> Option<&'a str> {
+        self.header
+            .map(|header| header.text.strip_suffix(':').unwrap_or(header.text))
+    }
+
+    /// Every line of the block, header first.
+    pub fn lines(&self) -> impl Iterator<Item = Line<'a>> + '_ {
+        self.header.into_iter().chain(self.body.iter().copied())
+    }
+
+    /// The first line of the block, header included, for pointing errors at.
+    pub fn first_line(&self) -> Line<'a> {
+        match self.header {
+            Some(header) => header,
+            None => self.body[0],
+        }
+    }
+}
+
+/// Puzzle input read from stdin, a file or a string. Line endings are normalised to `\n`,
+/// trailing whitespace is stripped from every line, and trailing blank lines are dropped,
+/// so parsers see the same text however the file was saved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PuzzleInput {
+    text: String,
+}
+
+impl PuzzleInput {
+    /// Reads every line from `reader`. The first IO error stops reading and is returned
+    /// with the number of the line it happened on, rather than truncating the input.
+    pub fn read(reader: impl BufRead) -> io::Result<PuzzleInput> {
+        let mut text = String::new();
+        for (index, line) in reader.lines().enumerate() {
+            let line = line
+                .map_err(|err| io::Error::new(err.kind(), format!("line {}: {err}", index + 1)))?;
+            text.push_str(line.trim_end());
+            text.push('\n');
+        }
+        Ok(PuzzleInput::normalised(&text))
+    }
+
+    pub fn stdin() -> io::Result<PuzzleInput> {
+        PuzzleInput::read(io::stdin().lock())
+    }
+
+    pub fn from_path(path: impl AsRef<Path>) -> io::Result<PuzzleInput> {
+        let path = path.as_ref();
+        let with_path =
+            |err: io::Error| io::Error::new(err.kind(), format!("{}: {err}", path.display()));
+        let file = File::open(path).map_err(with_path)?;
+        PuzzleInput::read(BufReader::new(file)).map_err(with_path)
+    }
+
+    fn normalised(text: &str) -> PuzzleInput {
+        let mut text: String = text
+            .lines()
+            .flat_map(|line| [line.trim_end(), "\n"])
+            .collect();
+        text.truncate(text.trim_end().len());
+        PuzzleInput { text }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = Line<'_>> {
+        self.text.lines().enumerate().map(|(index, text)| Line {
+            number: index + 1,
+            text,
+        })
+    }
+}
+
+impl PuzzleInput {
+    /// Splits the input into blank-line separated sections. Runs of blank lines count as
+    /// a single separator.
+    pub fn sections(&self) -> Vec<Section<'_>> {
+        let mut sections = Vec::new();
+        let mut block: Vec<Line> = Vec::new();
+        for line in self.lines().chain([Line {
+            number: 0,
+            text: "",
+        }]) {
+            if !line.text.is_empty() {
+                block.push(line);
+                continue;
+            }
+            if block.is_empty() {
+                continue;
+            }
+            let header = block[0].text.ends_with(':').then(|| block.remove(0));
+            sections.push(Section {
+                header,
+                body: std::mem::take(&mut block),
+            });
+        }
+        sections
+    }
+}
+
+impl From<&str> for PuzzleInput {
+    fn from(text: &str) -> PuzzleInput {
+        PuzzleInput::normalised(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_endings_and_trailing_whitespace_are_normalised() {
+        let input = PuzzleInput::from("a b \r\n\r\nc\t\r\n\n\n");
+        assert_eq!(input.as_str(), "a b\n\nc");
+        let numbers: Vec<(usize, &str)> = input.lines().map(|l| (l.number, l.text)).collect();
+        assert_eq!(numbers, [(1, "a b"), (2, ""), (3, "c")]);
+    }
+
+    #[test]
+    fn sections_split_on_blank_lines() {
+        let input = PuzzleInput::from("seeds: 1 2\n\n\nsoil map:\n1 2 3\n4 5 6\n\nend:");
+        let sections = input.sections();
+        assert_eq!(sections.len(), 3);
+        assert_eq!(sections[0].name(), None);
+        assert_eq!(sections[0].body[0].text, "seeds: 1 2");
+        assert_eq!(sections[1].name(), Some("soil map"));
+        let body: Vec<usize> = sections[1].body.iter().map(|l| l.number).collect();
+        assert_eq!(body, [5, 6]);
+        assert_eq!(sections[2].name(), Some("end"));
+        assert!(sections[2].body.is_empty());
+        assert_eq!(sections[2].first_line().number, 8);
+    }
+
+    #[test]
+    fn reading_matches_in_memory_input() {
+        let text = "first\r\nsecond  \n";
+        let read = PuzzleInput::read(text.as_bytes()).unwrap();
+        assert_eq!(read, PuzzleInput::from(text));
+    }
+
+    #[test]
+    fn invalid_utf8_reports_the_line() {
+        let bytes: &[u8] = b"ok\nbad \xff\n";
+        let err = PuzzleInput::read(bytes).unwrap_err();
+        assert!(err.to_string().starts_with("line 2:"), "{err}");
+    }
+}
@@ -0,0 +1,10 @@
+pub mod bench;
+mod error;
+mod grid;
+mod input;
+mod solution;
+
+pub use error::{parse_number, ParseError};
+pub use grid::{Coordinate, Grid};
+pub use input::{Line, PuzzleInput, Section};
+pub use solution::{main, print_answer, report, Error, Part, Solution};
@@ -0,0 +1,137 @@
+use crate::{ParseError, PuzzleInput};
+use std::env;
+use std::error;
+use std::fmt;
+use std::process;
+
+/// One day's puzzle: a parser shared by both parts, and the two parts themselves.
+pub trait Solution {
+    const DAY: u8;
+    /// The published example input for each part, used by tests and benchmarks.
+    const EXAMPLES: [&'static str; 2];
+    type Input;
+    type Answer: fmt::Display;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Result<Self::Answer, Error>;
+    fn part2(input: &Self::Input) -> Result<Self::Answer, Error>;
+
+    /// Solves `part`, along with observations made on the way that are worth telling the
+    /// user, printed to stderr before the answer. Days override this when a part has
+    /// something to report, so that the work behind a note is only done for that part.
+    fn solve(input: &Self::Input, part: Part) -> (Result<Self::Answer, Error>, Vec<String>) {
+        let answer = match part {
+            Part::One => Self::part1(input),
+            Part::Two => Self::part2(input),
+        };
+        (answer, Vec::new())
+    }
+
+    /// A detailed account of how `part` is solved, printed by `aoc run --explain`.
+    fn explain(_input: &Self::Input, _part: Part) -> Result<Vec<String>, Error> {
+        Ok(Vec::new())
+    }
+
+    /// Everything suspicious about `input`, including the parse error if it does not parse.
+    /// Days override this to flag inputs that parse but would be solved ambiguously.
+    fn check(input: &PuzzleInput) -> Vec<ParseError> {
+        Self::parse(input).err().into_iter().collect()
+    }
+}
+
+/// Anything that stops a day from producing an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Parse(ParseError),
+    Solve(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse(err) => write!(f, "{err}"),
+            Error::Solve(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Error {
+        Error::Parse(err)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// Expands a `1`, `2` or `both` selection into the parts to run, in order.
+    pub fn select(selection: &str) -> Option<&'static [Part]> {
+        match selection {
+            "1" => Some(&[Part::One]),
+            "2" => Some(&[Part::Two]),
+            "both" => Some(&[Part::One, Part::Two]),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+pub fn print_answer(day: u8, part: Part, answer: impl fmt::Display) {
+    println!("day {day} part {part}: {answer}");
+}
+
+/// Parses `input` once and prints the answer to each requested part. A failing part does
+/// not stop the others from running; the first failure is returned once all have run.
+pub fn report<S: Solution>(input: &PuzzleInput, parts: &[Part]) -> Result<(), Error> {
+    let parsed = S::parse(input)?;
+    let mut failure = None;
+    for part in parts {
+        let (answer, notes) = S::solve(&parsed, *part);
+        for note in notes {
+            eprintln!("day {}: {note}", S::DAY);
+        }
+        match answer {
+            Ok(answer) => print_answer(S::DAY, *part, answer),
+            Err(err) => {
+                println!("day {} part {part}: failed", S::DAY);
+                failure.get_or_insert(err);
+            }
+        }
+    }
+    match failure {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
+
+/// Entry point shared by the per-day binaries: reads the puzzle from stdin and runs the
+/// parts named by the optional first argument (`1`, `2` or `both`, the default).
+pub fn main<S: Solution>() {
+    let selection = env::args().nth(1).unwrap_or_else(|| String::from("both"));
+    let parts = Part::select(&selection).unwrap_or_else(|| {
+        eprintln!("error: unknown part {selection:?}, expected 1, 2 or both");
+        process::exit(2);
+    });
+    let input = PuzzleInput::stdin().unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        process::exit(1);
+    });
+    if let Err(err) = report::<S>(&input, parts) {
+        eprintln!("error: {err}");
+        process::exit(1);
+    }
+}
@@ -0,0 +1,12 @@
+//! Times every day against its example fixtures: `cargo bench -p aoc`.
+
+use aoc::{bench_day, DAYS};
+use aoc_core::bench;
+use std::time::Duration;
+
+fn main() {
+    let timings: Vec<_> = DAYS
+        .map(|day| bench_day(day, None, Duration::from_millis(200)).unwrap())
+        .collect();
+    bench::print_report(&timings);
+}
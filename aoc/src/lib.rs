@@ -0,0 +1,89 @@
+use aoc_core::bench::{self, Timings};
+use aoc_core::{report, Error, ParseError, Part, PuzzleInput, Solution};
+use std::ops::RangeInclusive;
+use std::time::Duration;
+
+pub const DAYS: RangeInclusive<u8> = 1..=8;
+
+fn unsolved(day: u8) -> Error {
+    Error::Solve(format!("day {day} is not solved yet"))
+}
+
+/// Evaluates `$body` with `$S` naming the `Solution` for `$day`, or fails for a day that
+/// has none. This is the one place that maps day numbers to crates.
+macro_rules! with_day {
+    ($day:expr, $S:ident => $body:expr) => {
+        match $day {
+            1 => {
+                type $S = day1puzzle2::Day1;
+                $body
+            }
+            2 => {
+                type $S = day2::Day2;
+                $body
+            }
+            3 => {
+                type $S = day3::Day3;
+                $body
+            }
+            4 => {
+                type $S = day4::Day4;
+                $body
+            }
+            5 => {
+                type $S = day5::Day5;
+                $body
+            }
+            6 => {
+                type $S = day6::Day6;
+                $body
+            }
+            7 => {
+                type $S = day7::Day7;
+                $body
+            }
+            8 => {
+                type $S = day8::Day8;
+                $body
+            }
+            day => Err(unsolved(day)),
+        }
+    };
+}
+
+pub fn run_day(day: u8, input: &PuzzleInput, parts: &[Part]) -> Result<(), Error> {
+    with_day!(day, S => report::<S>(input, parts))
+}
+
+/// Lists the problems `Solution::check` finds in `input` for one day.
+pub fn check_day(day: u8, input: &PuzzleInput) -> Result<Vec<ParseError>, Error> {
+    with_day!(day, S => Ok(S::check(input)))
+}
+
+/// Parses `input` for one day and collects its explanation of each of `parts`.
+pub fn explain_day(day: u8, input: &PuzzleInput, parts: &[Part]) -> Result<Vec<String>, Error> {
+    fn with<S: Solution>(input: &PuzzleInput, parts: &[Part]) -> Result<Vec<String>, Error> {
+        let parsed = S::parse(input)?;
+        let mut lines = Vec::new();
+        for &part in parts {
+            let explanation = S::explain(&parsed, part)?;
+            if !explanation.is_empty() {
+                lines.push(format!("day {} part {part}:", S::DAY));
+                lines.extend(explanation);
+            }
+        }
+        Ok(lines)
+    }
+    with_day!(day, S => with::<S>(input, parts))
+}
+
+/// Benchmarks one day against `input`, or against its example fixtures when no input is
+/// given.
+pub fn bench_day(day: u8, input: Option<&PuzzleInput>, budget: Duration) -> Result<Timings, Error> {
+    fn with<S: Solution>(input: Option<&PuzzleInput>, budget: Duration) -> Result<Timings, Error> {
+        let examples = S::EXAMPLES.map(PuzzleInput::from);
+        let inputs = input.map_or([&examples[0], &examples[1]], |input| [input; 2]);
+        bench::bench::<S>(inputs, budget)
+    }
+    with_day!(day, S => with::<S>(input, budget))
+}
@@ -0,0 +1,177 @@
+use aoc::{bench_day, check_day, explain_day, run_day, DAYS};
+use aoc_core::{bench, Part, PuzzleInput};
+use std::env;
+use std::io;
+use std::path::PathBuf;
+use std::process;
+use std::time::Duration;
+
+const USAGE: &str = "usage: aoc run <day> [--part 1|2|both] [--input path] [--check] [--explain]
+       aoc bench [<day>] [--input path | --input-dir dir] [--budget ms]";
+
+struct RunArgs {
+    day: u8,
+    parts: &'static [Part],
+    input: Option<String>,
+    check: bool,
+    explain: bool,
+}
+
+impl RunArgs {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<RunArgs, String> {
+        let day = args.next().ok_or("missing day")?;
+        let mut run = RunArgs {
+            day: parse_day(&day)?,
+            parts: Part::select("both").unwrap(),
+            input: None,
+            check: false,
+            explain: false,
+        };
+        while let Some(flag) = args.next() {
+            if flag == "--check" {
+                run.check = true;
+                continue;
+            }
+            if flag == "--explain" {
+                run.explain = true;
+                continue;
+            }
+            let value = args.next().ok_or_else(|| format!("{flag} needs a value"))?;
+            match flag.as_str() {
+                "--part" => {
+                    run.parts = Part::select(&value)
+                        .ok_or_else(|| format!("unknown part {value:?}, expected 1, 2 or both"))?
+                }
+                "--input" => run.input = Some(value),
+                _ => return Err(format!("unknown option {flag:?}")),
+            }
+        }
+        Ok(run)
+    }
+
+    fn read_input(&self) -> io::Result<PuzzleInput> {
+        match &self.input {
+            Some(path) => PuzzleInput::from_path(path),
+            None => PuzzleInput::stdin(),
+        }
+    }
+}
+
+struct BenchArgs {
+    day: Option<u8>,
+    input: Option<PathBuf>,
+    input_dir: Option<PathBuf>,
+    budget: Duration,
+}
+
+impl BenchArgs {
+    fn parse(args: impl Iterator<Item = String>) -> Result<BenchArgs, String> {
+        let mut args = args.peekable();
+        let mut bench = BenchArgs {
+            day: None,
+            input: None,
+            input_dir: None,
+            budget: Duration::from_millis(200),
+        };
+        if let Some(day) = args.next_if(|arg| !arg.starts_with("--")) {
+            bench.day = Some(parse_day(&day)?);
+        }
+        while let Some(flag) = args.next() {
+            let value = args.next().ok_or_else(|| format!("{flag} needs a value"))?;
+            match flag.as_str() {
+                "--input" => bench.input = Some(PathBuf::from(value)),
+                "--input-dir" => bench.input_dir = Some(PathBuf::from(value)),
+                "--budget" => {
+                    let millis = value
+                        .parse()
+                        .map_err(|_| format!("budget must be milliseconds, found {value:?}"))?;
+                    bench.budget = Duration::from_millis(millis);
+                }
+                _ => return Err(format!("unknown option {flag:?}")),
+            }
+        }
+        if bench.input.is_some() && (bench.day.is_none() || bench.input_dir.is_some()) {
+            return Err(String::from(
+                "--input needs a single day and no --input-dir",
+            ));
+        }
+        Ok(bench)
+    }
+
+    /// The input to benchmark `day` against: `--input`, then `<input-dir>/day<N>.txt` if it
+    /// exists, otherwise `None` for the day's example fixtures.
+    fn read_input(&self, day: u8) -> io::Result<Option<PuzzleInput>> {
+        if let Some(path) = &self.input {
+            return PuzzleInput::from_path(path).map(Some);
+        }
+        match &self.input_dir {
+            Some(dir) => {
+                let path = dir.join(format!("day{day}.txt"));
+                if path.exists() {
+                    PuzzleInput::from_path(path).map(Some)
+                } else {
+                    Ok(None)
+                }
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+fn parse_day(day: &str) -> Result<u8, String> {
+    day.parse()
+        .map_err(|_| format!("day must be a number, found {day:?}"))
+}
+
+fn fail(err: impl std::fmt::Display) -> ! {
+    eprintln!("error: {err}");
+    process::exit(1);
+}
+
+fn main() {
+    let mut args = env::args().skip(1);
+    let usage_error = |err: String| -> ! {
+        eprintln!("error: {err}\n{USAGE}");
+        process::exit(2);
+    };
+    match args.next().as_deref() {
+        Some("run") => {
+            let run = RunArgs::parse(args).unwrap_or_else(|err| usage_error(err));
+            let input = run.read_input().unwrap_or_else(|err| fail(err));
+            if run.check {
+                let problems = check_day(run.day, &input).unwrap_or_else(|err| fail(err));
+                for problem in problems.iter() {
+                    eprintln!("day {}: {problem}", run.day);
+                }
+                if !problems.is_empty() {
+                    fail(format!("{} problem(s) found in the input", problems.len()));
+                }
+            }
+            if let Err(err) = run_day(run.day, &input, run.parts) {
+                fail(err);
+            }
+            if run.explain {
+                let lines = explain_day(run.day, &input, run.parts).unwrap_or_else(|err| fail(err));
+                for line in lines {
+                    println!("{line}");
+                }
+            }
+        }
+        Some("bench") => {
+            let args = BenchArgs::parse(args).unwrap_or_else(|err| usage_error(err));
+            let days = match args.day {
+                Some(day) => day..=day,
+                None => DAYS,
+            };
+            let timings: Vec<_> = days
+                .map(|day| {
+                    let input = args.read_input(day).unwrap_or_else(|err| fail(err));
+                    bench_day(day, input.as_ref(), args.budget).unwrap_or_else(|err| fail(err))
+                })
+                .collect();
+            bench::print_report(&timings);
+        }
+        Some(command) => usage_error(format!("unknown command {command:?}")),
+        None => usage_error(String::from("missing command")),
+    }
+}
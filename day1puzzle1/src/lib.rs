@@ -0,0 +1,46 @@
+use aoc_core::{ParseError, PuzzleInput};
+use regex::Regex;
+
+/// Sums the calibration value of every line, reading only numeric digits.
+pub fn total(input: &PuzzleInput) -> Result<u32, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            read_num(line.text).ok_or_else(|| {
+                ParseError::at(line.number, line.text, line.text, "no digit in line")
+            })
+        })
+        .sum()
+}
+
+pub fn read_num(s: &str) -> Option<u32> {
+    let find_num: Regex = Regex::new(r"[0-9]").unwrap();
+    let numbers: Vec<&str> = find_num.find_iter(s).map(|m| m.as_str()).collect();
+    match (numbers.first(), numbers.last()) {
+        (Some(first), Some(last)) => format!("{first}{last}").parse().ok(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+    #[test]
+    fn example_answer() {
+        assert_eq!(total(&EXAMPLE.into()), Ok(142));
+    }
+
+    #[test]
+    fn single_digit_counts_twice() {
+        assert_eq!(read_num("treb7uchet"), Some(77));
+    }
+
+    #[test]
+    fn line_without_digits_is_an_error() {
+        let err = total(&"1abc2\nabc".into()).unwrap_err();
+        assert_eq!(err.line, 2);
+    }
+}
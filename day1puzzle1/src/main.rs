@@ -1,23 +1,16 @@
-use regex::Regex;
-use std::io;
+use aoc_core::{Part, PuzzleInput};
+use std::process;
 
 fn main() {
-    let mut total = 0;
-    for line in io::stdin().lines() {
-        total = total
-            + match line {
-                Ok(msg) => read_num(msg),
-                Err(_) => break,
-            }
+    let input = PuzzleInput::stdin().unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        process::exit(1);
+    });
+    match day1puzzle1::total(&input) {
+        Ok(total) => aoc_core::print_answer(1, Part::One, total),
+        Err(err) => {
+            eprintln!("error: {err}");
+            process::exit(1);
+        }
     }
-    println!("total: {total}")
-}
-
-fn read_num(s: String) -> u32 {
-    let find_num: Regex = Regex::new(r"[0-9]").unwrap();
-    let numbers: Vec<&str> = find_num.find_iter(&s).map(|m| m.as_str()).collect();
-    return match (numbers.first(), numbers.last()) {
-        (Some(first), Some(last)) => format!("{first}{last}").parse().unwrap(),
-        _ => 0,
-    };
 }
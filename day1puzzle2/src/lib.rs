@@ -0,0 +1,121 @@
+use aoc_core::{Error, ParseError, PuzzleInput, Solution};
+use regex::Regex;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    const EXAMPLES: [&'static str; 2] = [
+        include_str!("../fixtures/example1.txt"),
+        include_str!("../fixtures/example2.txt"),
+    ];
+    type Input = PuzzleInput;
+    type Answer = u32;
+
+    fn parse(input: &PuzzleInput) -> Result<PuzzleInput, ParseError> {
+        Ok(input.clone())
+    }
+
+    fn part1(input: &PuzzleInput) -> Result<u32, Error> {
+        Ok(day1puzzle1::total(input)?)
+    }
+
+    fn part2(input: &PuzzleInput) -> Result<u32, Error> {
+        Ok(total(input)?)
+    }
+}
+
+/// Sums the calibration value of every line, reading spelled-out digits as well.
+pub fn total(input: &PuzzleInput) -> Result<u32, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            read_num(line.text).ok_or_else(|| {
+                ParseError::at(
+                    line.number,
+                    line.text,
+                    line.text,
+                    "no digit or digit word in line",
+                )
+            })
+        })
+        .sum()
+}
+
+pub fn read_num(s: &str) -> Option<u32> {
+    let find_num: Regex =
+        Regex::new(r"([0-9]|one|two|three|four|five|six|seven|eight|nine)").unwrap();
+    let mut index = 0;
+    let mut first: Option<&str> = None;
+    let mut last: Option<&str> = None;
+    while index < s.len() {
+        match find_num.find_at(s, index) {
+            Some(found) => {
+                if first.is_none() {
+                    first = Some(found.as_str());
+                }
+                last = Some(found.as_str());
+                index = found.start() + 1;
+            }
+            None => break,
+        }
+    }
+    match (first, last) {
+        (Some(first), Some(last)) => {
+            let f = to_digit(first);
+            let l = to_digit(last);
+            format!("{f}{l}").parse().ok()
+        }
+        _ => None,
+    }
+}
+
+fn to_digit(s: &str) -> &str {
+    match s {
+        "one" => "1",
+        "two" => "2",
+        "three" => "3",
+        "four" => "4",
+        "five" => "5",
+        "six" => "6",
+        "seven" => "7",
+        "eight" => "8",
+        "nine" => "9",
+        _ => s,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = include_str!("../fixtures/example1.txt");
+    const EXAMPLE2: &str = include_str!("../fixtures/example2.txt");
+
+    #[test]
+    fn example_answers() {
+        assert_eq!(
+            Day1::part1(&Day1::parse(&EXAMPLE1.into()).unwrap()),
+            Ok(142)
+        );
+        assert_eq!(
+            Day1::part2(&Day1::parse(&EXAMPLE2.into()).unwrap()),
+            Ok(281)
+        );
+    }
+
+    #[test]
+    fn overlapping_words_count_at_both_ends() {
+        assert_eq!(read_num("eightwo"), Some(82));
+        assert_eq!(read_num("oneight"), Some(18));
+        assert_eq!(read_num("xtwone3four"), Some(24));
+        assert_eq!(read_num("sevenine"), Some(79));
+    }
+
+    #[test]
+    fn words_only_line_fails_part_one() {
+        let input = Day1::parse(&"two1nine\neightwothree".into()).unwrap();
+        assert!(Day1::part1(&input).is_err());
+        assert_eq!(Day1::part2(&input), Ok(29 + 83));
+    }
+}
@@ -0,0 +1,180 @@
+use aoc_core::{parse_number, Error, ParseError, PuzzleInput, Solution};
+use regex::Regex;
+use std::cmp::max;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const EXAMPLES: [&'static str; 2] = [include_str!("../fixtures/example.txt"); 2];
+    type Input = Vec<Game>;
+    type Answer = u32;
+
+    fn parse(input: &PuzzleInput) -> Result<Vec<Game>, ParseError> {
+        input
+            .lines()
+            .map(|line| Game::new(line.number, line.text))
+            .collect()
+    }
+
+    fn part1(games: &Vec<Game>) -> Result<u32, Error> {
+        let limit = Clue {
+            red: 12,
+            green: 13,
+            blue: 14,
+        };
+        Ok(games
+            .iter()
+            .filter(|game| game.possible(limit))
+            .map(|game| game.id)
+            .sum())
+    }
+
+    fn part2(games: &Vec<Game>) -> Result<u32, Error> {
+        Ok(games.iter().map(|game| game.minimum().power()).sum())
+    }
+}
+
+#[derive(Debug)]
+pub struct Game {
+    id: u32,
+    clues: Vec<Clue>,
+}
+
+impl Game {
+    fn new(line_number: usize, line: &str) -> Result<Game, ParseError> {
+        let num_re = Regex::new(r"\d+").unwrap();
+        let parts = line
+            .split_once(':')
+            .ok_or_else(|| ParseError::missing(line_number, line, "missing ':' delimiter"))?;
+        let id_match = num_re
+            .find(parts.0)
+            .ok_or_else(|| ParseError::at(line_number, line, parts.0, "missing game id"))?;
+        let id: u32 = parse_number(line_number, line, id_match.as_str())?;
+        let clues = parts
+            .1
+            .split(';')
+            .map(|clue| Clue::new(line_number, line, clue))
+            .collect::<Result<_, _>>()?;
+        Ok(Game { id, clues })
+    }
+
+    fn possible(&self, limit: Clue) -> bool {
+        self.clues.iter().all(|clue| {
+            clue.red <= limit.red && clue.green <= limit.green && clue.blue <= limit.blue
+        })
+    }
+
+    fn minimum(&self) -> Clue {
+        self.clues
+            .iter()
+            .copied()
+            .reduce(|total, clue| Clue {
+                red: max(total.red, clue.red),
+                green: max(total.green, clue.green),
+                blue: max(total.blue, clue.blue),
+            })
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct Clue {
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+
+enum Color {
+    Blue,
+    Red,
+    Green,
+}
+
+impl Clue {
+    fn new(line_number: usize, line: &str, data: &str) -> Result<Clue, ParseError> {
+        let clue_re = Regex::new(r"^\s*(\d+)\s+(\w+)\s*$").unwrap();
+        data.split(',')
+            .map(|part| {
+                let (num, color) = match clue_re.captures(part) {
+                    Some(c) => {
+                        let (_, [num, color]) = c.extract();
+                        (num, color)
+                    }
+                    None => {
+                        return Err(ParseError::at(
+                            line_number,
+                            line,
+                            part.trim(),
+                            "expected '<count> <color>'",
+                        ))
+                    }
+                };
+                let color = Color::from_str(color)
+                    .ok_or_else(|| ParseError::at(line_number, line, color, "unknown color"))?;
+                Ok((parse_number(line_number, line, num)?, color))
+            })
+            .try_fold(
+                Clue {
+                    red: 0,
+                    green: 0,
+                    blue: 0,
+                },
+                |clue, update| {
+                    Ok(match update? {
+                        (num, Color::Red) => Clue {
+                            red: num,
+                            green: clue.green,
+                            blue: clue.blue,
+                        },
+                        (num, Color::Blue) => Clue {
+                            red: clue.red,
+                            green: clue.green,
+                            blue: num,
+                        },
+                        (num, Color::Green) => Clue {
+                            red: clue.red,
+                            green: num,
+                            blue: clue.blue,
+                        },
+                    })
+                },
+            )
+    }
+
+    fn power(&self) -> u32 {
+        self.red * self.green * self.blue
+    }
+}
+
+impl Color {
+    fn from_str(name: &str) -> Option<Color> {
+        match name {
+            "red" => Some(Color::Red),
+            "green" => Some(Color::Green),
+            "blue" => Some(Color::Blue),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+    #[test]
+    fn example_answers() {
+        let games = Day2::parse(&EXAMPLE.into()).unwrap();
+        assert_eq!(Day2::part1(&games), Ok(8));
+        assert_eq!(Day2::part2(&games), Ok(2286));
+    }
+
+    #[test]
+    fn unknown_color_is_reported() {
+        let err = Day2::parse(&"Game 1: 3 blue, 4 purple".into()).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 19));
+        assert_eq!(err.text, "purple");
+    }
+}
@@ -0,0 +1,141 @@
+use aoc_core::{parse_number, Coordinate, Error, Grid, ParseError, PuzzleInput, Solution};
+use regex::Regex;
+use std::collections::HashMap;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    const EXAMPLES: [&'static str; 2] = [include_str!("../fixtures/example.txt"); 2];
+    type Input = Schematic;
+    type Answer = usize;
+
+    fn parse(input: &PuzzleInput) -> Result<Schematic, ParseError> {
+        Schematic::new(input)
+    }
+
+    fn part1(schematic: &Schematic) -> Result<usize, Error> {
+        Ok(schematic
+            .parts
+            .iter()
+            .filter(|p| {
+                schematic
+                    .surroundings(p)
+                    .any(|(_, &c)| c != b'.' && !c.is_ascii_digit())
+            })
+            .map(|p| p.val)
+            .sum())
+    }
+
+    fn part2(schematic: &Schematic) -> Result<usize, Error> {
+        let mut gears: HashMap<Coordinate, Gear> = HashMap::new();
+        for p in schematic.parts.iter() {
+            for (coor, &c) in schematic.surroundings(p) {
+                if c == b'*' {
+                    gears.insert(
+                        coor,
+                        match gears.get(&coor) {
+                            Some(g) => g.include(p.val),
+                            None => Gear::Incomplete(p.val),
+                        },
+                    );
+                }
+            }
+        }
+        Ok(gears
+            .values()
+            .map(|g| match g {
+                &Gear::Complete(first, second) => first * second,
+                _ => 0,
+            })
+            .sum())
+    }
+}
+
+pub struct Schematic {
+    grid: Grid<u8>,
+    parts: Vec<Part>,
+}
+
+impl Schematic {
+    fn new(input: &PuzzleInput) -> Result<Schematic, ParseError> {
+        let grid = Grid::parse(input)?;
+        let find_num = Regex::new("[0-9]+").unwrap();
+        let mut parts = Vec::new();
+        for line in input.lines() {
+            let text = line.text;
+            if let Some(bad) = text.char_indices().find(|(_, c)| !c.is_ascii_graphic()) {
+                return Err(ParseError::at(
+                    line.number,
+                    text,
+                    &text[bad.0..bad.0 + 1],
+                    "unexpected character in schematic",
+                ));
+            }
+            for num in find_num.find_iter(text) {
+                parts.push(Part {
+                    row: line.number - 1,
+                    start: num.start(),
+                    end: num.end(),
+                    val: parse_number(line.number, text, num.as_str())?,
+                });
+            }
+        }
+        Ok(Schematic { grid, parts })
+    }
+
+    /// Every cell touching the part, diagonals included.
+    fn surroundings<'a>(&'a self, part: &Part) -> impl Iterator<Item = (Coordinate, &'a u8)> {
+        self.grid.ring(part.row..part.row + 1, part.start..part.end)
+    }
+}
+
+struct Part {
+    row: usize,
+    start: usize,
+    end: usize,
+    val: usize,
+}
+
+enum Gear {
+    Incomplete(usize),
+    Complete(usize, usize),
+    OverLimit,
+}
+
+impl Gear {
+    fn include(&self, num: usize) -> Gear {
+        match self {
+            Self::Incomplete(first) => Self::Complete(*first, num),
+            _ => Self::OverLimit,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+    #[test]
+    fn example_answers() {
+        let schematic = Day3::parse(&EXAMPLE.into()).unwrap();
+        assert_eq!(Day3::part1(&schematic), Ok(4361));
+        assert_eq!(Day3::part2(&schematic), Ok(467835));
+    }
+
+    #[test]
+    fn gear_touching_three_numbers_is_not_a_gear() {
+        let schematic = Day3::parse(&"1.2\n.*.\n3..".into()).unwrap();
+        assert_eq!(Day3::part1(&schematic), Ok(6));
+        assert_eq!(Day3::part2(&schematic), Ok(0));
+    }
+
+    #[test]
+    fn numbers_on_the_edges_are_found() {
+        let schematic = Day3::parse(&"12*\n...\n*34".into()).unwrap();
+        assert_eq!(Day3::part1(&schematic), Ok(46));
+        assert_eq!(Day3::part2(&schematic), Ok(0));
+    }
+}
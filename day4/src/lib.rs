@@ -0,0 +1,381 @@
+use aoc_core::{parse_number, Error, ParseError, Part, PuzzleInput, Solution};
+use std::collections::HashSet;
+use std::fmt;
+use std::ops::RangeInclusive;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    const EXAMPLES: [&'static str; 2] = [include_str!("../fixtures/example.txt"); 2];
+    type Input = Vec<Card>;
+    type Answer = usize;
+
+    /// Cards must be numbered 1, 2, 3... in order, as the cascade in part 2 wins copies of
+    /// the cards that follow by position.
+    fn parse(input: &PuzzleInput) -> Result<Vec<Card>, ParseError> {
+        let mut cards: Vec<Card> = Vec::new();
+        for line in input.lines() {
+            let card = Card::new(line.number, line.text)?;
+            let expected = cards.len() + 1;
+            if card.id != expected {
+                let front = line
+                    .text
+                    .split_once(':')
+                    .map_or(line.text, |(front, _)| front);
+                return Err(ParseError::at(
+                    line.number,
+                    line.text,
+                    id_field(front).unwrap_or(front),
+                    format!("expected card {expected}"),
+                ));
+            }
+            cards.push(card);
+        }
+        Ok(cards)
+    }
+
+    fn part1(cards: &Vec<Card>) -> Result<usize, Error> {
+        cards.iter().try_fold(0usize, |total, card| {
+            let points = card.points().ok_or_else(|| {
+                Error::Solve(format!("card {} scores too many points to count", card.id))
+            })?;
+            total
+                .checked_add(points)
+                .ok_or_else(|| Error::Solve(String::from("total points overflow")))
+        })
+    }
+
+    fn part2(cards: &Vec<Card>) -> Result<usize, Error> {
+        Ok(Scratchcards::play(cards, Overflow::Error)?.total())
+    }
+
+    fn explain(cards: &Vec<Card>, part: Part) -> Result<Vec<String>, Error> {
+        match part {
+            Part::One => Ok(Vec::new()),
+            Part::Two => {
+                let played = Scratchcards::play(cards, Overflow::Error)?;
+                Ok(played.trace().iter().map(Spawn::to_string).collect())
+            }
+        }
+    }
+}
+
+/// What to do when a card wins copies of cards past the end of the table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overflow {
+    /// Win only the cards that exist.
+    Clamp,
+    /// Refuse the table, which the puzzle promises never happens.
+    Error,
+}
+
+/// The outcome of playing every card and every copy won, in order. Cards are numbered by
+/// their position in the table, from 1, which parsing checks matches their ids.
+pub struct Scratchcards {
+    copies: Vec<usize>,
+    trace: Vec<Spawn>,
+}
+
+/// One card's winnings: each of its `instances` wins one copy of every card in `won`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Spawn {
+    pub card: usize,
+    pub instances: usize,
+    pub won: RangeInclusive<usize>,
+}
+
+impl fmt::Display for Spawn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let plural = if self.instances == 1 { "y" } else { "ies" };
+        write!(
+            f,
+            "card {} ({} cop{plural}) wins ",
+            self.card, self.instances
+        )?;
+        if self.won.start() == self.won.end() {
+            write!(f, "card {}", self.won.start())
+        } else {
+            write!(f, "cards {}-{}", self.won.start(), self.won.end())
+        }
+    }
+}
+
+impl Scratchcards {
+    pub fn play(cards: &[Card], overflow: Overflow) -> Result<Scratchcards, Error> {
+        let mut copies = vec![1; cards.len()];
+        let mut trace = Vec::new();
+        for (index, card) in cards.iter().enumerate() {
+            let following = cards.len() - index - 1;
+            let mut matches = card.matches();
+            if matches > following {
+                match overflow {
+                    Overflow::Clamp => matches = following,
+                    Overflow::Error => {
+                        return Err(Error::Solve(format!(
+                            "card {} wins {matches} cards but only {following} follow it",
+                            index + 1
+                        )))
+                    }
+                }
+            }
+            if matches == 0 {
+                continue;
+            }
+            for won in index + 1..=index + matches {
+                copies[won] += copies[index];
+            }
+            trace.push(Spawn {
+                card: index + 1,
+                instances: copies[index],
+                won: index + 2..=index + matches + 1,
+            });
+        }
+        Ok(Scratchcards { copies, trace })
+    }
+
+    /// How many of each card, original included, ended up in play.
+    pub fn copies(&self) -> &[usize] {
+        &self.copies
+    }
+
+    pub fn total(&self) -> usize {
+        self.copies.iter().sum()
+    }
+
+    /// Every card that won something, in the order they were scored.
+    pub fn trace(&self) -> &[Spawn] {
+        &self.trace
+    }
+}
+
+/// A scratchcard, reduced when parsed to how many of its numbers win.
+pub struct Card {
+    id: usize,
+    matches: usize,
+}
+
+impl Card {
+    fn new(line_number: usize, line: &str) -> Result<Card, ParseError> {
+        let (front, nums) = line
+            .split_once(':')
+            .ok_or_else(|| ParseError::missing(line_number, line, "missing ':' delimiter"))?;
+        let id_str = id_field(front)
+            .ok_or_else(|| ParseError::at(line_number, line, front, "expected 'Card <id>'"))?;
+        let (targets, has) = nums
+            .split_once('|')
+            .ok_or_else(|| ParseError::missing(line_number, line, "missing '|' delimiter"))?;
+        let mut target = NumberSet::default();
+        for t in targets.split_whitespace() {
+            if !target.insert(parse_number(line_number, line, t)?) {
+                return Err(ParseError::at(
+                    line_number,
+                    line,
+                    t,
+                    "winning number is listed twice",
+                ));
+            }
+        }
+        // A number we have twice wins twice, which the set intersection alone would miss.
+        let mut contains = NumberSet::default();
+        let mut repeated_wins = 0;
+        for h in has.split_whitespace() {
+            let num = parse_number(line_number, line, h)?;
+            if !contains.insert(num) && target.contains(num) {
+                repeated_wins += 1;
+            }
+        }
+        Ok(Card {
+            id: parse_number(line_number, line, id_str)?,
+            matches: target.intersection_len(&contains) + repeated_wins,
+        })
+    }
+
+    /// `None` when the score does not fit in a `usize`.
+    fn points(&self) -> Option<usize> {
+        match self.matches {
+            0 => Some(0),
+            _ => 1usize.checked_shl(u32::try_from(self.matches - 1).ok()?),
+        }
+    }
+
+    fn matches(&self) -> usize {
+        self.matches
+    }
+}
+
+/// A set of card numbers: a bitset for everything below 128, which covers real inputs, and a
+/// hash set for anything larger.
+#[derive(Default)]
+struct NumberSet {
+    small: u128,
+    large: HashSet<usize>,
+}
+
+impl NumberSet {
+    /// Adds `num`, returning whether it was new.
+    fn insert(&mut self, num: usize) -> bool {
+        match num {
+            0..128 => {
+                let new = !self.contains(num);
+                self.small |= 1 << num;
+                new
+            }
+            _ => self.large.insert(num),
+        }
+    }
+
+    fn contains(&self, num: usize) -> bool {
+        match num {
+            0..128 => self.small & (1 << num) != 0,
+            _ => self.large.contains(&num),
+        }
+    }
+
+    fn intersection_len(&self, other: &NumberSet) -> usize {
+        (self.small & other.small).count_ones() as usize
+            + self.large.intersection(&other.large).count()
+    }
+}
+
+/// The id in a card's `Card <id>` label, however much padding precedes it.
+fn id_field(front: &str) -> Option<&str> {
+    front
+        .strip_prefix("Card")
+        .filter(|rest| rest.starts_with(char::is_whitespace))
+        .map(str::trim)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+    #[test]
+    fn example_answers() {
+        let cards = Day4::parse(&EXAMPLE.into()).unwrap();
+        assert_eq!(Day4::part1(&cards), Ok(13));
+        assert_eq!(Day4::part2(&cards), Ok(30));
+    }
+
+    #[test]
+    fn example_copies_and_trace() {
+        let cards = Day4::parse(&EXAMPLE.into()).unwrap();
+        let played = Scratchcards::play(&cards, Overflow::Error).unwrap();
+        assert_eq!(played.copies(), &[1, 2, 4, 8, 14, 1]);
+        let trace: Vec<String> = played.trace().iter().map(Spawn::to_string).collect();
+        assert_eq!(
+            trace,
+            [
+                "card 1 (1 copy) wins cards 2-5",
+                "card 2 (2 copies) wins cards 3-4",
+                "card 3 (4 copies) wins cards 4-5",
+                "card 4 (8 copies) wins card 5",
+            ]
+        );
+    }
+
+    #[test]
+    fn winning_past_the_end_follows_the_policy() {
+        let input = "Card 1: 1 2 | 1 2\nCard 2: 1 2 3 | 1 2 3";
+        let cards = Day4::parse(&input.into()).unwrap();
+        let clamped = Scratchcards::play(&cards, Overflow::Clamp).unwrap();
+        assert_eq!(clamped.copies(), &[1, 2]);
+        assert_eq!(
+            Scratchcards::play(&cards, Overflow::Error).err(),
+            Some(Error::Solve(String::from(
+                "card 1 wins 2 cards but only 1 follow it"
+            )))
+        );
+        assert!(Day4::part2(&cards).is_err());
+    }
+
+    #[test]
+    fn padded_ids_are_read() {
+        let card = Card::new(12, "Card   12: 1 2 | 2 3").unwrap();
+        assert_eq!(card.id, 12);
+        assert_eq!(card.matches(), 1);
+    }
+
+    #[test]
+    fn malformed_cards_are_rejected() {
+        let cases = [
+            ("Card 1 1 2 | 2 3", 1, 17, "missing ':'"),
+            ("Card 1: 1 2 2 3", 1, 16, "missing '|'"),
+            ("Cards 1: 1 | 2", 1, 1, "expected 'Card <id>'"),
+            ("Card x: 1 | 2", 1, 6, "expected a number"),
+            ("Card 1: 5 7  5 | 2", 1, 14, "listed twice"),
+            ("Card 1: 1 | 2\nCard 3: 1 | 2", 2, 6, "expected card 2"),
+            ("Card  2: 1 | 2", 1, 7, "expected card 1"),
+        ];
+        for (input, line, column, message) in cases {
+            let err = Day4::parse(&input.into()).err().unwrap();
+            assert_eq!((err.line, err.column), (line, column), "{input:?}: {err}");
+            assert!(err.message.contains(message), "{input:?}: {err}");
+        }
+    }
+
+    #[test]
+    fn large_and_repeated_numbers_are_counted() {
+        let card = Card::new(1, "Card 1: 0 127 128 5000 | 127 128 5000 0 3").unwrap();
+        assert_eq!(card.matches(), 4);
+        assert_eq!(card.points(), Some(8));
+        let card = Card::new(1, "Card 1: 9 300 | 9 9 300 300 1").unwrap();
+        assert_eq!(card.matches(), 4);
+        let err = Card::new(1, "Card 1: 300 9 300 | 1").err().unwrap();
+        assert_eq!(err.column, 15);
+    }
+
+    #[test]
+    fn number_set_agrees_with_a_hash_set() {
+        let mut bits = NumberSet::default();
+        let mut reference = HashSet::new();
+        for num in (0..400).step_by(7).chain((0..400).step_by(11)) {
+            assert_eq!(bits.insert(num), reference.insert(num), "{num}");
+        }
+        for num in 0..400 {
+            assert_eq!(bits.contains(num), reference.contains(&num), "{num}");
+        }
+        let mut odd = NumberSet::default();
+        (1..400).step_by(2).for_each(|num| {
+            odd.insert(num);
+        });
+        let expected = reference.iter().filter(|&&num| num % 2 == 1).count();
+        assert_eq!(bits.intersection_len(&odd), expected);
+    }
+
+    #[test]
+    fn card_without_matches_scores_nothing() {
+        let card = Card::new(1, "Card 1: 1 2 3 | 4 5 6").unwrap();
+        assert_eq!(card.matches(), 0);
+        assert_eq!(card.points(), Some(0));
+    }
+
+    #[test]
+    fn scores_too_large_to_count_are_reported() {
+        let card_with = |id: usize, matches: usize| {
+            let nums: Vec<String> = (0..matches).map(|num| num.to_string()).collect();
+            let nums = nums.join(" ");
+            format!("Card {id}: {nums} | {nums}")
+        };
+        let input = format!("{}\n{}", card_with(1, 64), card_with(2, 1));
+        let cards = Day4::parse(&input.as_str().into()).unwrap();
+        assert_eq!(Day4::part1(&cards), Ok((1 << 63) + 1));
+
+        let input = format!("{}\n{}", card_with(1, 64), card_with(2, 64));
+        let cards = Day4::parse(&input.as_str().into()).unwrap();
+        assert_eq!(
+            Day4::part1(&cards),
+            Err(Error::Solve(String::from("total points overflow")))
+        );
+
+        let cards = Day4::parse(&card_with(1, 70).as_str().into()).unwrap();
+        assert_eq!(
+            Day4::part1(&cards),
+            Err(Error::Solve(String::from(
+                "card 1 scores too many points to count"
+            )))
+        );
+    }
+}
@@ -0,0 +1,750 @@
+use aoc_core::{parse_number, Error, Line, ParseError, PuzzleInput, Section, Solution};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::ops::Range;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    const EXAMPLES: [&'static str; 2] = [include_str!("../fixtures/example.txt"); 2];
+    type Input = (Vec<isize>, Almanac);
+    type Answer = isize;
+
+    fn parse(input: &PuzzleInput) -> Result<(Vec<isize>, Almanac), ParseError> {
+        parse(input)
+    }
+
+    /// Overlapping source ranges are resolved by line order and overlapping destination
+    /// ranges make a map non-injective, so both are reported. Every map line is checked
+    /// on its own, so one bad line does not hide the problems on the others; only a bad
+    /// seeds line or section header stops the check.
+    fn check(input: &PuzzleInput) -> Vec<ParseError> {
+        let sections = input.sections();
+        if let Err(err) = parse_seeds(&sections) {
+            return vec![err];
+        }
+        let mut problems = Vec::new();
+        let mut categories = HashSet::new();
+        for section in sections.iter().skip(1) {
+            match parse_header(section) {
+                Ok(key) if !categories.insert(key) => {
+                    problems.push(duplicate_map(section.first_line()))
+                }
+                Ok(_) => {}
+                Err(err) => {
+                    problems.push(err);
+                    return problems;
+                }
+            }
+            let mut maps: Vec<(Line, AlmanacMap)> = Vec::new();
+            for line in section.body.iter() {
+                match parse_mapping(*line) {
+                    Ok(m) => maps.push((*line, m)),
+                    Err(err) => problems.push(err),
+                }
+            }
+            problems.extend(overlaps(&maps, |m| m.start..m.end, "source"));
+            problems.extend(overlaps(
+                &maps,
+                |m| m.start + m.delta..m.end + m.delta,
+                "destination",
+            ));
+        }
+        problems
+    }
+
+    fn part1((seeds, almanac): &(Vec<isize>, Almanac)) -> Result<isize, Error> {
+        let locations = almanac.conversion("seed", "location")?.flatten();
+        seeds
+            .iter()
+            .map(|seed| locations.apply(*seed))
+            .min()
+            .ok_or_else(|| Error::Solve(String::from("no seeds")))
+    }
+
+    fn part2((seeds, almanac): &(Vec<isize>, Almanac)) -> Result<isize, Error> {
+        almanac
+            .conversion("seed", "location")?
+            .apply_ranges(seed_ranges(seeds)?)
+            .iter()
+            .map(|r| r.start)
+            .min()
+            .ok_or_else(|| Error::Solve(String::from("no seed ranges")))
+    }
+}
+
+struct AlmanacMap {
+    start: isize,
+    end: isize,
+    delta: isize,
+}
+
+impl AlmanacMap {
+    /// `None` when either end of the source or destination range overflows.
+    fn new(start: isize, target: isize, range: isize) -> Option<AlmanacMap> {
+        target.checked_add(range)?;
+        Some(AlmanacMap {
+            start,
+            end: start.checked_add(range)?,
+            delta: target.checked_sub(start)?,
+        })
+    }
+
+    fn invert(&self) -> AlmanacMap {
+        AlmanacMap {
+            start: self.start + self.delta,
+            end: self.end + self.delta,
+            delta: -self.delta,
+        }
+    }
+}
+
+struct AlmanacSection {
+    a_maps: Vec<AlmanacMap>,
+}
+
+impl AlmanacSection {
+    fn apply(&self, val: isize) -> isize {
+        for m in self.a_maps.iter() {
+            if m.start <= val && m.end > val {
+                return val + m.delta;
+            }
+        }
+        val
+    }
+
+    /// Pushes every half-open range through the section, splitting ranges that straddle a
+    /// map boundary. Like `apply`, the first map covering a value wins and values no map
+    /// covers pass through unchanged.
+    fn apply_ranges(&self, ranges: Vec<Range<isize>>) -> Vec<Range<isize>> {
+        let mut mapped = Vec::new();
+        let mut pending = ranges;
+        for m in self.a_maps.iter() {
+            let mut unmapped = Vec::new();
+            for r in pending {
+                let overlap = r.start.max(m.start)..r.end.min(m.end);
+                if overlap.is_empty() {
+                    unmapped.push(r);
+                    continue;
+                }
+                mapped.push(overlap.start + m.delta..overlap.end + m.delta);
+                if r.start < overlap.start {
+                    unmapped.push(r.start..overlap.start);
+                }
+                if overlap.end < r.end {
+                    unmapped.push(overlap.end..r.end);
+                }
+            }
+            pending = unmapped;
+        }
+        mapped.extend(pending);
+        mapped
+    }
+
+    /// Maps each destination range back onto its source range. This only undoes `apply`
+    /// when the section is injective: no two source ranges and no two destination ranges
+    /// overlap, and no destination range covers a value that passes through unmapped.
+    fn invert(&self) -> AlmanacSection {
+        AlmanacSection {
+            a_maps: self.a_maps.iter().map(AlmanacMap::invert).collect(),
+        }
+    }
+
+    fn flatten(&self) -> PiecewiseMap {
+        let breakpoints = self.a_maps.iter().flat_map(|m| [m.start, m.end]);
+        PiecewiseMap::sample(breakpoints, |val| self.apply(val))
+    }
+}
+
+/// A section reduced to sorted, non-overlapping pieces, with every value outside them
+/// passing through unchanged. Prints in the almanac's own `<destination> <source> <length>`
+/// form.
+pub struct PiecewiseMap {
+    pieces: Vec<AlmanacMap>,
+}
+
+impl PiecewiseMap {
+    /// Builds the map from a function that is a plain shift between consecutive breakpoints
+    /// and the identity outside them.
+    fn sample(
+        breakpoints: impl Iterator<Item = isize>,
+        f: impl Fn(isize) -> isize,
+    ) -> PiecewiseMap {
+        let mut breakpoints: Vec<isize> = breakpoints.collect();
+        breakpoints.sort_unstable();
+        breakpoints.dedup();
+        let mut pieces: Vec<AlmanacMap> = Vec::new();
+        for window in breakpoints.windows(2) {
+            let (start, end) = (window[0], window[1]);
+            let delta = f(start) - start;
+            if delta == 0 {
+                continue;
+            }
+            match pieces.last_mut() {
+                Some(last) if last.end == start && last.delta == delta => last.end = end,
+                _ => pieces.push(AlmanacMap { start, end, delta }),
+            }
+        }
+        PiecewiseMap { pieces }
+    }
+
+    pub fn apply(&self, val: isize) -> isize {
+        let after = self.pieces.partition_point(|m| m.start <= val);
+        match after.checked_sub(1).map(|i| &self.pieces[i]) {
+            Some(m) if m.end > val => val + m.delta,
+            _ => val,
+        }
+    }
+
+    /// The map equivalent to applying `self` and then `next`.
+    pub fn compose(&self, next: &PiecewiseMap) -> PiecewiseMap {
+        // The result can only change shift where `self` does, or where `self` carries a
+        // value onto one of `next`'s boundaries.
+        let mut breakpoints = Vec::new();
+        for m in self.pieces.iter() {
+            breakpoints.extend([m.start, m.end]);
+        }
+        for n in next.pieces.iter() {
+            for boundary in [n.start, n.end] {
+                breakpoints.push(boundary);
+                breakpoints.extend(
+                    self.pieces
+                        .iter()
+                        .filter(|m| m.start + m.delta <= boundary && boundary <= m.end + m.delta)
+                        .map(|m| boundary - m.delta),
+                );
+            }
+        }
+        PiecewiseMap::sample(breakpoints.into_iter(), |val| next.apply(self.apply(val)))
+    }
+}
+
+impl fmt::Display for PiecewiseMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for m in self.pieces.iter() {
+            writeln!(f, "{} {} {}", m.start + m.delta, m.start, m.end - m.start)?;
+        }
+        Ok(())
+    }
+}
+
+/// Every map in the almanac, keyed by its `(source, destination)` categories.
+pub struct Almanac {
+    sections: BTreeMap<(String, String), AlmanacSection>,
+}
+
+impl Almanac {
+    /// Swaps every map's direction, so that e.g. `location -> seed` becomes a conversion.
+    pub fn invert(&self) -> Almanac {
+        Almanac {
+            sections: self
+                .sections
+                .iter()
+                .map(|((source, destination), section)| {
+                    ((destination.clone(), source.clone()), section.invert())
+                })
+                .collect(),
+        }
+    }
+
+    /// Finds the shortest chain of maps converting `from` values into `to` values, whatever
+    /// order they appeared in the file.
+    pub fn conversion(&self, from: &str, to: &str) -> Result<Conversion<'_>, Error> {
+        let mut reached: HashMap<&str, Option<(&str, &AlmanacSection)>> = HashMap::new();
+        let mut queue = VecDeque::from([from]);
+        reached.insert(from, None);
+        while let Some(category) = queue.pop_front() {
+            if category == to {
+                break;
+            }
+            for ((source, destination), section) in self.sections.iter() {
+                if source == category && !reached.contains_key(destination.as_str()) {
+                    reached.insert(destination, Some((category, section)));
+                    queue.push_back(destination);
+                }
+            }
+        }
+        if !reached.contains_key(to) {
+            return Err(Error::Solve(format!("no conversion from {from} to {to}")));
+        }
+
+        let mut sections = Vec::new();
+        let mut category = to;
+        while let Some(&Some((previous, section))) = reached.get(category) {
+            sections.push(section);
+            category = previous;
+        }
+        sections.reverse();
+        Ok(Conversion { sections })
+    }
+}
+
+/// A chain of almanac maps leading from one category to another.
+pub struct Conversion<'a> {
+    sections: Vec<&'a AlmanacSection>,
+}
+
+impl Conversion<'_> {
+    pub fn apply(&self, val: isize) -> isize {
+        self.sections.iter().fold(val, |val, sec| sec.apply(val))
+    }
+
+    pub fn apply_ranges(&self, ranges: Vec<Range<isize>>) -> Vec<Range<isize>> {
+        self.sections
+            .iter()
+            .fold(ranges, |ranges, sec| sec.apply_ranges(ranges))
+    }
+
+    /// Composes the whole chain into one map, so each lookup is a single binary search.
+    pub fn flatten(&self) -> PiecewiseMap {
+        self.sections
+            .iter()
+            .fold(PiecewiseMap { pieces: Vec::new() }, |flat, sec| {
+                flat.compose(&sec.flatten())
+            })
+    }
+}
+
+fn parse(input: &PuzzleInput) -> Result<(Vec<isize>, Almanac), ParseError> {
+    let sections = input.sections();
+    let seeds = parse_seeds(&sections)?;
+    let mut almanac = Almanac {
+        sections: BTreeMap::new(),
+    };
+    for section in sections.iter().skip(1) {
+        let (source, destination) = parse_header(section)?;
+        let a_maps = section
+            .body
+            .iter()
+            .map(|line| parse_mapping(*line))
+            .collect::<Result<_, _>>()?;
+        let key = (source.to_string(), destination.to_string());
+        if almanac
+            .sections
+            .insert(key, AlmanacSection { a_maps })
+            .is_some()
+        {
+            return Err(duplicate_map(section.first_line()));
+        }
+    }
+    Ok((seeds, almanac))
+}
+
+/// The seeds listed in the first section, which must be a lone `seeds:` line.
+fn parse_seeds(sections: &[Section]) -> Result<Vec<isize>, ParseError> {
+    let seeds_section = sections
+        .first()
+        .ok_or_else(|| ParseError::new(1, 1, "", "missing seeds"))?;
+    let first = seeds_section.first_line();
+    let seed_list = match (seeds_section.header, seeds_section.body.len()) {
+        (None, 1) => first.text.strip_prefix("seeds:"),
+        _ => None,
+    }
+    .ok_or_else(|| ParseError::at(first.number, first.text, first.text, "expected 'seeds:'"))?;
+    seed_list
+        .split_whitespace()
+        .map(|s| parse_whole(first, s))
+        .collect()
+}
+
+/// The source and destination categories named by a `<source>-to-<destination> map:`
+/// header.
+fn parse_header<'a>(section: &Section<'a>) -> Result<(&'a str, &'a str), ParseError> {
+    let header = section.first_line();
+    section
+        .name()
+        .and_then(|name| name.strip_suffix(" map"))
+        .and_then(|name| name.split_once("-to-"))
+        .ok_or_else(|| {
+            ParseError::at(
+                header.number,
+                header.text,
+                header.text,
+                "expected a '<source>-to-<destination> map:' header",
+            )
+        })
+}
+
+fn duplicate_map(header: Line) -> ParseError {
+    ParseError::at(
+        header.number,
+        header.text,
+        header.text,
+        "a map between these categories already exists",
+    )
+}
+
+fn parse_mapping(line: Line) -> Result<AlmanacMap, ParseError> {
+    let nums: Vec<isize> = line
+        .text
+        .split_whitespace()
+        .map(|s| parse_whole(line, s))
+        .collect::<Result<_, _>>()?;
+    if nums.len() != 3 {
+        return Err(ParseError::at(
+            line.number,
+            line.text,
+            line.text,
+            "expected '<destination> <source> <length>'",
+        ));
+    }
+    AlmanacMap::new(nums[1], nums[0], nums[2]).ok_or_else(|| {
+        ParseError::at(
+            line.number,
+            line.text,
+            line.text,
+            "range overflows a machine integer",
+        )
+    })
+}
+
+/// Parses `token`, a slice of `line`, as a seed or map value, which are never negative.
+fn parse_whole(line: Line, token: &str) -> Result<isize, ParseError> {
+    if !token.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(ParseError::at(
+            line.number,
+            line.text,
+            token,
+            "expected a non-negative whole number",
+        ));
+    }
+    parse_number(line.number, line.text, token)
+}
+
+/// Reports every map whose `kind` range overlaps one earlier in the sort order.
+fn overlaps(
+    maps: &[(Line, AlmanacMap)],
+    range: impl Fn(&AlmanacMap) -> Range<isize>,
+    kind: &str,
+) -> Vec<ParseError> {
+    let mut sorted: Vec<(Line, Range<isize>)> = maps
+        .iter()
+        .map(|(line, m)| (*line, range(m)))
+        .filter(|(_, r)| !r.is_empty())
+        .collect();
+    sorted.sort_by_key(|(_, r)| r.start);
+    let mut problems = Vec::new();
+    let mut furthest: Option<(Line, isize)> = None;
+    for (line, r) in sorted {
+        match furthest {
+            Some((other, end)) if r.start < end => {
+                problems.push(ParseError::at(
+                    line.number,
+                    line.text,
+                    line.text,
+                    format!("{kind} range overlaps the one on line {}", other.number),
+                ));
+                if r.end > end {
+                    furthest = Some((line, r.end));
+                }
+            }
+            _ => furthest = Some((line, r.end)),
+        }
+    }
+    problems
+}
+
+/// Reads the seeds as `<start> <length>` pairs, refusing a dangling start, a range with
+/// no seeds in it, or a range whose end overflows rather than quietly dropping it.
+fn seed_ranges(seeds: &[isize]) -> Result<Vec<Range<isize>>, Error> {
+    if !seeds.len().is_multiple_of(2) {
+        return Err(Error::Solve(format!(
+            "seed {} has no range length to pair with",
+            seeds[seeds.len() - 1]
+        )));
+    }
+    seeds
+        .chunks_exact(2)
+        .map(|pair| {
+            if pair[1] <= 0 {
+                return Err(Error::Solve(format!(
+                    "seed range {} {} holds no seeds",
+                    pair[0], pair[1]
+                )));
+            }
+            let end = pair[0].checked_add(pair[1]).ok_or_else(|| {
+                Error::Solve(format!("seed range {} {} overflows", pair[0], pair[1]))
+            })?;
+            Ok(pair[0]..end)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+    fn example() -> (Vec<isize>, Almanac) {
+        parse(&EXAMPLE.into()).unwrap()
+    }
+
+    /// Small xorshift generator so the sampled checks are reproducible without extra crates.
+    struct Sampler(u64);
+
+    impl Sampler {
+        fn next(&mut self, bound: isize) -> isize {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            (self.0 % bound as u64) as isize
+        }
+    }
+
+    #[test]
+    fn example_answers() {
+        let input = Day5::parse(&EXAMPLE.into()).unwrap();
+        assert_eq!(Day5::part1(&input), Ok(35));
+        assert_eq!(Day5::part2(&input), Ok(46));
+    }
+
+    #[test]
+    fn unpaired_or_overflowing_seeds_are_reported() {
+        let input = EXAMPLE.replacen("seeds: 79 14 55 13", "seeds: 79 14 55", 1);
+        let parsed = Day5::parse(&input.as_str().into()).unwrap();
+        assert!(Day5::part1(&parsed).is_ok());
+        assert_eq!(
+            Day5::part2(&parsed),
+            Err(Error::Solve(String::from(
+                "seed 55 has no range length to pair with"
+            )))
+        );
+        assert!(seed_ranges(&[isize::MAX, 2]).is_err());
+        assert_eq!(
+            seed_ranges(&[79, 14, 1, 0]),
+            Err(Error::Solve(String::from("seed range 1 0 holds no seeds")))
+        );
+        assert_eq!(
+            seed_ranges(&[79, 14, 1, -5]),
+            Err(Error::Solve(String::from("seed range 1 -5 holds no seeds")))
+        );
+        let input = EXAMPLE.replacen("seeds: 79 14 55 13", "seeds: 79 14 1 0", 1);
+        let parsed = Day5::parse(&input.as_str().into()).unwrap();
+        assert!(Day5::part2(&parsed).is_err());
+    }
+
+    #[test]
+    fn sections_are_chained_by_category() {
+        let input = "seeds: 1 5\n\nsoil-to-location map:\n10 0 100\n\nseed-to-soil map:\n0 1 1";
+        let (seeds, almanac) = parse(&input.into()).unwrap();
+        let locations = almanac.conversion("seed", "location").unwrap();
+        assert_eq!(locations.apply(seeds[0]), 10);
+        assert_eq!(locations.apply(seeds[1]), 15);
+    }
+
+    #[test]
+    fn any_conversion_along_the_chain() {
+        let (_, almanac) = example();
+        // Soil 14 is fertilizer 53, water 49, light 42, temperature 42 and humidity 43.
+        assert_eq!(
+            almanac.conversion("soil", "humidity").unwrap().apply(14),
+            43
+        );
+        assert_eq!(almanac.conversion("light", "light").unwrap().apply(7), 7);
+    }
+
+    #[test]
+    fn missing_conversion_is_reported() {
+        let input = "seeds: 1\n\nseed-to-soil map:\n0 1 1\n\nwater-to-location map:\n0 1 1";
+        let (_, almanac) = parse(&input.into()).unwrap();
+        assert!(almanac.conversion("seed", "soil").is_ok());
+        assert_eq!(
+            almanac.conversion("seed", "location").err(),
+            Some(Error::Solve(String::from(
+                "no conversion from seed to location"
+            )))
+        );
+        assert!(almanac.conversion("soil", "seed").is_err());
+    }
+
+    #[test]
+    fn duplicate_map_is_reported() {
+        let input = "seeds: 1\n\nseed-to-soil map:\n0 1 1\n\nseed-to-soil map:\n0 1 1";
+        let err = parse(&input.into()).err().unwrap();
+        assert_eq!(err.line, 6);
+    }
+
+    #[test]
+    fn inverse_round_trips_sampled_seeds() {
+        let (_, almanac) = example();
+        let inverse = almanac.invert();
+        let forward = almanac.conversion("seed", "location").unwrap();
+        let backward = inverse.conversion("location", "seed").unwrap();
+        let mut sampler = Sampler(0x1a7e);
+        for _ in 0..500 {
+            let seed = sampler.next(200);
+            assert_eq!(backward.apply(forward.apply(seed)), seed, "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn searching_locations_upward_finds_part2() {
+        let (seeds, almanac) = example();
+        let inverse = almanac.invert();
+        let seeds_of = inverse.conversion("location", "seed").unwrap();
+        let ranges = seed_ranges(&seeds).unwrap();
+        let lowest = (0..)
+            .find(|location| {
+                let seed = seeds_of.apply(*location);
+                ranges.iter().any(|r| r.contains(&seed))
+            })
+            .unwrap();
+        assert_eq!(Ok(lowest), Day5::part2(&(seeds, almanac)));
+    }
+
+    #[test]
+    fn example_passes_check() {
+        assert_eq!(Day5::check(&EXAMPLE.into()), vec![]);
+    }
+
+    #[test]
+    fn check_reports_overlapping_ranges() {
+        let input = "seeds: 1\n\nseed-to-soil map:\n100 0 10\n300 20 5\n200 5 10\n105 40 1";
+        let problems = Day5::check(&input.into());
+        let found: Vec<(usize, &str)> = problems
+            .iter()
+            .map(|p| (p.line, p.message.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (6, "source range overlaps the one on line 4"),
+                (7, "destination range overlaps the one on line 4"),
+            ]
+        );
+    }
+
+    #[test]
+    fn overflowing_range_is_a_parse_error() {
+        let input = format!("seeds: 1\n\nseed-to-soil map:\n0 {} 2", isize::MAX - 1);
+        let problems = Day5::check(&input.as_str().into());
+        assert_eq!(problems.len(), 1);
+        assert_eq!(problems[0].line, 4);
+        assert!(problems[0].message.contains("overflows"));
+    }
+
+    #[test]
+    fn negative_values_are_parse_errors() {
+        let cases = [
+            ("seeds: 79 -14\n\nseed-to-soil map:\n50 98 2", 1, 11, "-14"),
+            ("seeds: 79 14\n\nseed-to-soil map:\n-50 98 2", 4, 1, "-50"),
+            ("seeds: 79 14\n\nseed-to-soil map:\n50 -98 2", 4, 4, "-98"),
+            ("seeds: 79 14\n\nseed-to-soil map:\n50 98 -48", 4, 7, "-48"),
+        ];
+        for (input, line, column, token) in cases {
+            let err = Day5::parse(&input.into()).err().unwrap();
+            assert_eq!((err.line, err.column), (line, column), "{input:?}: {err}");
+            assert_eq!(err.text, token);
+            assert!(err.message.contains("non-negative"), "{input:?}: {err}");
+            assert_eq!(Day5::check(&input.into()), vec![err]);
+        }
+    }
+
+    #[test]
+    fn check_reports_every_bad_line() {
+        let input = format!(
+            "seeds: 1\n\nseed-to-soil map:\n0 {max} 2\n100 0 10\n0 {max} 3\n200 5 10\n\n\
+             soil-to-water map:\n0 0 10\n5 20 10\n\nseed-to-soil map:\n0 0 1",
+            max = isize::MAX - 1
+        );
+        let problems = Day5::check(&input.as_str().into());
+        let found: Vec<(usize, &str)> = problems
+            .iter()
+            .map(|p| (p.line, p.message.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (4, "range overflows a machine integer"),
+                (6, "range overflows a machine integer"),
+                (7, "source range overlaps the one on line 5"),
+                (11, "destination range overlaps the one on line 10"),
+                (13, "a map between these categories already exists"),
+            ]
+        );
+        let input = "seeds: 1\n\nseed-to-soil:\n0 0 -1\n\nsoil-to-water map:\n0 0 -1";
+        let problems = Day5::check(&input.into());
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].message.contains("header"));
+    }
+
+    #[test]
+    fn flattened_section_is_sorted_and_printable() {
+        let (_, almanac) = example();
+        let soil = almanac.conversion("seed", "soil").unwrap().flatten();
+        assert_eq!(soil.to_string(), "52 50 48\n50 98 2\n");
+    }
+
+    #[test]
+    fn overlapping_maps_flatten_by_first_match() {
+        let input = "seeds: 1\n\nseed-to-soil map:\n100 0 10\n200 5 10";
+        let (_, almanac) = parse(&input.into()).unwrap();
+        let soil = almanac.conversion("seed", "soil").unwrap().flatten();
+        assert_eq!(soil.to_string(), "100 0 10\n205 10 5\n");
+    }
+
+    #[test]
+    fn flattened_chain_agrees_with_sections() {
+        let (_, almanac) = example();
+        let locations = almanac.conversion("seed", "location").unwrap();
+        let flat = locations.flatten();
+        for pair in flat.pieces.windows(2) {
+            assert!(pair[0].end <= pair[1].start);
+        }
+        let mut sampler = Sampler(0xf1a7);
+        for _ in 0..500 {
+            let seed = sampler.next(200) - 50;
+            assert_eq!(flat.apply(seed), locations.apply(seed), "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn ranges_preserve_total_length() {
+        let (seeds, almanac) = example();
+        let almanac = almanac.conversion("seed", "location").unwrap();
+        let input = seed_ranges(&seeds).unwrap();
+        let before: isize = input.iter().map(|r| r.end - r.start).sum();
+        let after: isize = almanac
+            .apply_ranges(input)
+            .iter()
+            .map(|r| r.end - r.start)
+            .sum();
+        assert_eq!(before, after);
+    }
+
+    #[test]
+    fn ranges_agree_with_scalar_apply() {
+        let (_, almanac) = example();
+        let almanac = almanac.conversion("seed", "location").unwrap();
+        let mut sampler = Sampler(0x2023_1205);
+        for _ in 0..500 {
+            let start = sampler.next(120);
+            let range = start..start + 1 + sampler.next(40);
+            let mut expected: Vec<isize> = range.clone().map(|s| almanac.apply(s)).collect();
+            let mut actual: Vec<isize> = almanac
+                .apply_ranges(vec![range.clone()])
+                .into_iter()
+                .flatten()
+                .collect();
+            expected.sort();
+            actual.sort();
+            assert_eq!(expected, actual, "seeds {range:?}");
+        }
+    }
+
+    #[test]
+    fn single_seed_ranges_match_apply() {
+        let (_, almanac) = example();
+        let almanac = almanac.conversion("seed", "location").unwrap();
+        let mut sampler = Sampler(0x5eed);
+        for _ in 0..500 {
+            let seed = sampler.next(200);
+            let location = almanac.apply(seed);
+            let single = seed..seed + 1;
+            let expected = location..location + 1;
+            assert_eq!(almanac.apply_ranges(vec![single]), vec![expected]);
+        }
+    }
+}
@@ -0,0 +1,507 @@
+use aoc_core::{Error, Line, ParseError, PuzzleInput, Solution};
+use std::ops::RangeInclusive;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    const EXAMPLES: [&'static str; 2] = [include_str!("../fixtures/example.txt"); 2];
+    /// The races read as columns, and the one long race read with the kerning ignored.
+    /// Joining the columns can overflow where each column alone fits, so that reading
+    /// keeps its error for part 2 rather than failing both parts.
+    type Input = (Vec<Race>, Result<Race, ParseError>);
+    type Answer = u64;
+
+    fn parse(input: &PuzzleInput) -> Result<(Vec<Race>, Result<Race, ParseError>), ParseError> {
+        let sheet = Sheet::parse(input)?;
+        let kerned = sheet
+            .races(Reading::Kerned)
+            .map(|mut races| races.remove(0));
+        Ok((sheet.races(Reading::Columns)?, kerned))
+    }
+
+    fn part1((races, _): &(Vec<Race>, Result<Race, ParseError>)) -> Result<u64, Error> {
+        if races.is_empty() {
+            return Err(Error::Solve(String::from("no races")));
+        }
+        races
+            .iter()
+            .try_fold(1u64, |acc, race| acc.checked_mul(race.ways_to_win()))
+            .ok_or_else(|| Error::Solve(String::from("product of ways overflows")))
+    }
+
+    fn part2((_, race): &(Vec<Race>, Result<Race, ParseError>)) -> Result<u64, Error> {
+        race.as_ref()
+            .map(Race::ways_to_win)
+            .map_err(|err| Error::Parse(err.clone()))
+    }
+
+    /// Each distance should sit right-aligned under its time, as in the published inputs.
+    fn check(input: &PuzzleInput) -> Vec<ParseError> {
+        let sheet = match Sheet::parse(input) {
+            Ok(sheet) => sheet,
+            Err(err) => return vec![err],
+        };
+        if let Err(err) = sheet.races(Reading::Kerned) {
+            return vec![err];
+        }
+        sheet
+            .times
+            .columns
+            .iter()
+            .zip(sheet.distances.columns.iter())
+            .filter(|(time, distance)| sheet.times.end_of(time) != sheet.distances.end_of(distance))
+            .map(|(time, distance)| {
+                let line = sheet.distances.line;
+                ParseError::at(
+                    line.number,
+                    line.text,
+                    distance,
+                    format!("distance is not aligned under the time {time}"),
+                )
+            })
+            .collect()
+    }
+}
+
+/// How the digits on each row are read: as separate races, or with the spaces between
+/// them ignored as bad kerning, making one long race.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reading {
+    Columns,
+    Kerned,
+}
+
+/// The `Time:` and `Distance:` rows of the race sheet.
+struct Sheet<'a> {
+    times: Row<'a>,
+    distances: Row<'a>,
+}
+
+struct Row<'a> {
+    line: Line<'a>,
+    /// Everything after the label, from the first digit to the last.
+    values: &'a str,
+    columns: Vec<&'a str>,
+}
+
+impl<'a> Sheet<'a> {
+    fn parse(input: &'a PuzzleInput) -> Result<Sheet<'a>, ParseError> {
+        let mut lines = input.lines();
+        let time_line = lines
+            .next()
+            .ok_or_else(|| ParseError::new(1, 1, "", "missing time row"))?;
+        let distance_line = lines.next().ok_or_else(|| {
+            ParseError::missing(time_line.number, time_line.text, "missing distance row")
+        })?;
+        if let Some(extra) = lines.next() {
+            return Err(ParseError::at(
+                extra.number,
+                extra.text,
+                extra.text,
+                "unexpected line after the distance row",
+            ));
+        }
+        let times = Row::parse(time_line, "Time:")?;
+        let distances = Row::parse(distance_line, "Distance:")?;
+        if times.columns.len() != distances.columns.len() {
+            return Err(ParseError::at(
+                distance_line.number,
+                distance_line.text,
+                distance_line.text,
+                format!(
+                    "found {} distances for {} times",
+                    distances.columns.len(),
+                    times.columns.len()
+                ),
+            ));
+        }
+        Ok(Sheet { times, distances })
+    }
+
+    fn races(&self, reading: Reading) -> Result<Vec<Race>, ParseError> {
+        let times = self.times.numbers(reading)?;
+        let distances = self.distances.numbers(reading)?;
+        Ok(times
+            .into_iter()
+            .zip(distances)
+            .map(|(time, record)| Race::new(time, record))
+            .collect())
+    }
+}
+
+impl<'a> Row<'a> {
+    /// Splits a row into its columns after the `label`, checking each is a whole number.
+    fn parse(line: Line<'a>, label: &str) -> Result<Row<'a>, ParseError> {
+        let values = line.text.strip_prefix(label).ok_or_else(|| {
+            let found = line.text.split_whitespace().next().unwrap_or(line.text);
+            ParseError::at(line.number, line.text, found, format!("expected '{label}'"))
+        })?;
+        let values = values.trim_start();
+        let columns: Vec<&str> = values.split_whitespace().collect();
+        if columns.is_empty() {
+            return Err(ParseError::missing(
+                line.number,
+                line.text,
+                "no races on this row",
+            ));
+        }
+        if let Some(bad) = columns
+            .iter()
+            .find(|column| !column.bytes().all(|b| b.is_ascii_digit()))
+        {
+            return Err(ParseError::at(
+                line.number,
+                line.text,
+                bad,
+                "expected a non-negative whole number",
+            ));
+        }
+        Ok(Row {
+            line,
+            values,
+            columns,
+        })
+    }
+
+    fn numbers(&self, reading: Reading) -> Result<Vec<u64>, ParseError> {
+        let too_large = |text: &str| {
+            ParseError::at(
+                self.line.number,
+                self.line.text,
+                text,
+                "number does not fit in 64 bits",
+            )
+        };
+        match reading {
+            Reading::Columns => self
+                .columns
+                .iter()
+                .map(|column| column.parse().map_err(|_| too_large(column)))
+                .collect(),
+            Reading::Kerned => {
+                let number = self
+                    .columns
+                    .concat()
+                    .parse()
+                    .map_err(|_| too_large(self.values))?;
+                Ok(vec![number])
+            }
+        }
+    }
+
+    /// The 1-based position of the last character of `column`, one of this row's columns.
+    fn end_of(&self, column: &str) -> usize {
+        column.as_ptr() as usize + column.len() - self.line.text.as_ptr() as usize
+    }
+}
+
+/// A boat race: the boat gains `acceleration` mm/ms of speed for every millisecond the
+/// button is held, starting from `initial_speed` and never exceeding `max_speed`, then
+/// travels at that speed for the rest of the race.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Race {
+    pub time: u64,
+    pub record: u64,
+    pub acceleration: u64,
+    pub initial_speed: u64,
+    pub max_speed: Option<u64>,
+}
+
+/// The best a race can go: the shortest hold reaching the furthest distance, and how far
+/// that distance beats the record by (negative when the record is unbeatable).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Optimum {
+    pub hold: u64,
+    pub distance: u128,
+    pub margin: i128,
+}
+
+impl Race {
+    /// The puzzle's race: 1 mm/ms gained per millisecond held, from a standstill.
+    pub fn new(time: u64, record: u64) -> Race {
+        Race {
+            time,
+            record,
+            acceleration: 1,
+            initial_speed: 0,
+            max_speed: None,
+        }
+    }
+
+    pub fn with_acceleration(self, acceleration: u64) -> Race {
+        Race {
+            acceleration,
+            ..self
+        }
+    }
+
+    pub fn with_initial_speed(self, initial_speed: u64) -> Race {
+        Race {
+            initial_speed,
+            ..self
+        }
+    }
+
+    pub fn with_max_speed(self, max_speed: u64) -> Race {
+        Race {
+            max_speed: Some(max_speed),
+            ..self
+        }
+    }
+
+    /// How far the boat gets when the button is held for `hold` ms, saturating rather than
+    /// overflowing for absurd parameters.
+    pub fn distance(&self, hold: u64) -> u128 {
+        if hold >= self.time {
+            return 0;
+        }
+        let speed = (self.acceleration as u128)
+            .saturating_mul(hold as u128)
+            .saturating_add(self.initial_speed as u128);
+        let speed = self.max_speed.map_or(speed, |max| speed.min(max as u128));
+        speed.saturating_mul((self.time - hold) as u128)
+    }
+
+    /// The distance is concave in the hold time (the smaller of two concave curves once
+    /// speed is capped), so the first hold that does no better than its successor is the
+    /// best one.
+    pub fn optimum(&self) -> Optimum {
+        let hold = first(0, self.time, |hold| {
+            self.distance(hold) >= self.distance(hold + 1)
+        });
+        let distance = self.distance(hold);
+        Optimum {
+            hold,
+            distance,
+            margin: i128::try_from(distance).unwrap_or(i128::MAX) - self.record as i128,
+        }
+    }
+
+    /// Every hold time that beats the record, empty when none does. Winners form one run
+    /// around the optimum, whose ends are found by binary search on either side of it.
+    pub fn winning_holds(&self) -> RangeInclusive<u64> {
+        let best = self.optimum().hold;
+        let beats = |hold| self.distance(hold) > self.record as u128;
+        if !beats(best) {
+            return RangeInclusive::new(1, 0);
+        }
+        let lower = first(0, best, beats);
+        let upper = first(best, self.time, |hold| !beats(hold)) - 1;
+        lower..=upper
+    }
+
+    pub fn ways_to_win(&self) -> u64 {
+        let holds = self.winning_holds();
+        if holds.is_empty() {
+            0
+        } else {
+            holds.end() - holds.start() + 1
+        }
+    }
+}
+
+/// The first value in `low..=high` satisfying `pred`, which must be false and then true
+/// across the range; `high` when only it could be.
+fn first(mut low: u64, mut high: u64, pred: impl Fn(u64) -> bool) -> u64 {
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if pred(mid) {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+    low
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+    #[test]
+    fn example_answers() {
+        let races = Day6::parse(&EXAMPLE.into()).unwrap();
+        assert_eq!(Day6::part1(&races), Ok(288));
+        assert_eq!(Day6::part2(&races), Ok(71503));
+    }
+
+    #[test]
+    fn kerned_reading_joins_the_columns() {
+        let sheet_input = PuzzleInput::from(EXAMPLE);
+        let sheet = Sheet::parse(&sheet_input).unwrap();
+        assert_eq!(
+            sheet.races(Reading::Columns).unwrap(),
+            vec![Race::new(7, 9), Race::new(15, 40), Race::new(30, 200)]
+        );
+        assert_eq!(
+            sheet.races(Reading::Kerned).unwrap(),
+            vec![Race::new(71530, 940200)]
+        );
+    }
+
+    #[test]
+    fn malformed_sheets_are_rejected() {
+        let cases = [
+            ("Time: 7\nDistance: 9\nTime: 8", 3, 1, "unexpected line"),
+            ("Time: 7", 1, 8, "missing distance row"),
+            ("Times: 7\nDistance: 9", 1, 1, "expected 'Time:'"),
+            (
+                "Time: 7 15\nDistance: 9",
+                2,
+                1,
+                "found 1 distances for 2 times",
+            ),
+            (
+                "Time: 7 -15\nDistance: 9 40",
+                1,
+                9,
+                "non-negative whole number",
+            ),
+            (
+                "Time: 7 1.5\nDistance: 9 40",
+                1,
+                9,
+                "non-negative whole number",
+            ),
+            ("Time:\nDistance:", 1, 6, "no races"),
+        ];
+        for (input, line, column, message) in cases {
+            let err = Day6::parse(&input.into()).err().unwrap();
+            assert_eq!((err.line, err.column), (line, column), "{input:?}: {err}");
+            assert!(err.message.contains(message), "{input:?}: {err}");
+        }
+    }
+
+    #[test]
+    fn kerned_overflow_only_fails_part2() {
+        let input = "Time: 100000000000000000 7 7 7\nDistance: 1 9 9 9";
+        let races = Day6::parse(&input.into()).unwrap();
+        assert_eq!(
+            Day6::part1(&races),
+            Ok((100000000000000000 - 1) * 4 * 4 * 4)
+        );
+        let Err(Error::Parse(err)) = Day6::part2(&races) else {
+            panic!("the kerned reading should not fit in 64 bits");
+        };
+        assert_eq!((err.line, err.column), (1, 7));
+        assert_eq!(err.text, "100000000000000000 7 7 7");
+    }
+
+    #[test]
+    fn overflowing_product_is_reported() {
+        let input = "Time: 10000000 10000000 10000000\nDistance: 1 1 1";
+        let races = Day6::parse(&input.into()).unwrap();
+        assert_eq!(
+            Day6::part1(&races),
+            Err(Error::Solve(String::from("product of ways overflows")))
+        );
+    }
+
+    #[test]
+    fn check_reports_misaligned_columns() {
+        assert_eq!(Day6::check(&EXAMPLE.into()), vec![]);
+        let problems = Day6::check(&"Time:      7  15\nDistance:  9 40".into());
+        assert_eq!(problems.len(), 1);
+        assert_eq!((problems[0].line, problems[0].column), (2, 14));
+    }
+
+    #[test]
+    fn exact_record_roots_are_excluded() {
+        // Holding 10 or 20 ms of a 30 ms race exactly ties the 200 mm record.
+        assert_eq!(Race::new(30, 200).winning_holds(), 11..=19);
+    }
+
+    #[test]
+    fn unbeatable_record_has_no_winners() {
+        for race in [Race::new(3, 2), Race::new(10, 25), Race::new(0, 0)] {
+            assert!(race.winning_holds().is_empty(), "{race:?}");
+            assert_eq!(race.ways_to_win(), 0);
+        }
+        assert_eq!(
+            Race::new(10, 1000).optimum(),
+            Optimum {
+                hold: 5,
+                distance: 25,
+                margin: -975
+            }
+        );
+    }
+
+    #[test]
+    fn exact_beyond_f64_precision() {
+        // Past 2^53 an f64 cannot tell `record` from `record + 1`, so the boundary cases
+        // below would be rounded onto the wrong side.
+        let time = (1 << 40) + 7;
+        let hold = 1_000_003;
+        let distance = hold * (time - hold);
+        let holds = Race::new(time, distance - 1).winning_holds();
+        assert_eq!(holds, hold..=time - hold);
+        let holds = Race::new(time, distance).winning_holds();
+        assert_eq!(holds, hold + 1..=time - hold - 1);
+        assert_eq!(Race::new(u64::MAX, 0).winning_holds(), 1..=u64::MAX - 1);
+    }
+
+    #[test]
+    fn optimum_of_the_example_races() {
+        let race = Race::new(7, 9);
+        assert_eq!(race.optimum().hold, 3);
+        assert_eq!(race.optimum().margin, 3);
+    }
+
+    #[test]
+    fn speed_limits_and_head_starts() {
+        // Capped at 3 mm/ms, holding longer than 3 ms only loses time.
+        let capped = Race::new(10, 20).with_max_speed(3);
+        assert_eq!(capped.optimum().hold, 3);
+        assert_eq!(capped.winning_holds(), 3..=3);
+        // Already moving at 5 mm/ms, not holding at all covers 50 mm.
+        let rolling = Race::new(10, 49).with_initial_speed(5);
+        assert_eq!(rolling.winning_holds(), 0..=5);
+        let fast = Race::new(10, 49).with_acceleration(3);
+        assert_eq!(fast.optimum().hold, 5);
+        assert_eq!(fast.winning_holds(), 3..=7);
+    }
+
+    #[test]
+    fn matches_brute_force_on_small_races() {
+        let models = [
+            (1, 0, None),
+            (3, 0, None),
+            (2, 5, None),
+            (1, 0, Some(4)),
+            (4, 2, Some(9)),
+        ];
+        for (acceleration, initial_speed, max_speed) in models {
+            for time in 0..40u64 {
+                for record in 0..200 {
+                    let race = Race {
+                        time,
+                        record,
+                        acceleration,
+                        initial_speed,
+                        max_speed,
+                    };
+                    let distances: Vec<u128> = (0..=time)
+                        .map(|hold| {
+                            let speed = (initial_speed + acceleration * hold)
+                                .min(max_speed.unwrap_or(u64::MAX));
+                            (speed * (time - hold)) as u128
+                        })
+                        .collect();
+                    let winners: Vec<u64> = (0..=time)
+                        .filter(|&hold| distances[hold as usize] > record as u128)
+                        .collect();
+                    assert_eq!(race.ways_to_win(), winners.len() as u64, "{race:?}");
+                    if let (Some(&lower), Some(&upper)) = (winners.first(), winners.last()) {
+                        assert_eq!(race.winning_holds(), lower..=upper, "{race:?}");
+                    }
+                    let best = distances.iter().max().unwrap();
+                    assert_eq!(race.optimum().distance, *best, "{race:?}");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,712 @@
+use aoc_core::{parse_number, Error, ParseError, Part, PuzzleInput, Solution};
+use std::cmp::{Ordering, Reverse};
+use std::fmt;
+use std::marker::PhantomData;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    const EXAMPLES: [&'static str; 2] = [include_str!("../fixtures/example.txt"); 2];
+    type Input = Vec<(Hand, usize)>;
+    type Answer = usize;
+
+    fn parse(input: &PuzzleInput) -> Result<Vec<(Hand, usize)>, ParseError> {
+        input
+            .lines()
+            .map(|line| parse_line(line.number, line.text))
+            .collect()
+    }
+
+    fn part1(hands: &Vec<(Hand, usize)>) -> Result<usize, Error> {
+        total_winnings::<Standard>(hands)
+    }
+
+    fn part2(hands: &Vec<(Hand, usize)>) -> Result<usize, Error> {
+        total_winnings::<JokersWild>(hands)
+    }
+
+    fn explain(hands: &Vec<(Hand, usize)>, part: Part) -> Result<Vec<String>, Error> {
+        match part {
+            Part::One => ranking_table::<Standard>(hands),
+            Part::Two => ranking_table::<JokersWild>(hands),
+        }
+    }
+}
+
+fn total_winnings<R: Rules>(hands: &[(Hand, usize)]) -> Result<usize, Error> {
+    Ok(winnings(&rank::<R>(hands)?))
+}
+
+/// Every hand with its rank, type and winnings, and what put it above the hand ranked
+/// just below it.
+fn ranking_table<R: Rules>(hands: &[(Hand, usize)]) -> Result<Vec<String>, Error> {
+    let ranked = rank::<R>(hands)?;
+    let mut table = vec![format!(
+        "{:>5}  {:<5}  {:>6}  {:>9}  {:<11}  explanation",
+        "rank", "hand", "bid", "winnings", "beats below"
+    )];
+    for (index, (hand, bid)) in ranked.iter().enumerate() {
+        let decided = match index
+            .checked_sub(1)
+            .map(|below| hand.decide(&ranked[below].0))
+        {
+            None => String::from("-"),
+            Some(Decision::Type) => String::from("on type"),
+            Some(Decision::Card(position)) => format!("on card {}", position + 1),
+            Some(Decision::Tie) => String::from("tied"),
+        };
+        table.push(format!(
+            "{:>5}  {:<5}  {:>6}  {:>9}  {:<11}  {}",
+            index + 1,
+            hand.to_string(),
+            bid,
+            (index + 1) * bid,
+            decided,
+            hand.explain()
+        ));
+    }
+    Ok(table)
+}
+
+/// Sorts the hands under rules `R`, weakest first.
+fn rank<R: Rules>(hands: &[(Hand, usize)]) -> Result<Vec<(Hand<R>, usize)>, Error> {
+    let mut ranked: Vec<(Hand<R>, usize)> = hands
+        .iter()
+        .map(|(hand, bid)| {
+            let hand = hand
+                .with_rules()
+                .map_err(|err| Error::Solve(format!("hand {hand}: {err}")))?;
+            Ok((hand, *bid))
+        })
+        .collect::<Result<_, Error>>()?;
+    ranked.sort_unstable_by(|(left, _), (right, _)| left.cmp(right));
+    Ok(ranked)
+}
+
+/// Total winnings of hands already sorted from weakest to strongest.
+fn winnings<H>(ranked: &[(H, usize)]) -> usize {
+    ranked
+        .iter()
+        .enumerate()
+        .map(|v| (v.0 + 1, v.1))
+        .fold(0, |acc, row| acc + row.0 * row.1 .1)
+}
+
+/// A variant of Camel Cards: the deck, how many cards make a hand, which cards are wild,
+/// and how hands of the same type are split. Rule sets are plain marker types chosen
+/// through `Hand<R>`.
+pub trait Rules: fmt::Debug + Clone + Copy + PartialEq + Eq {
+    /// The deck's alphabet: every card, weakest first. Wild cards rank by their place here
+    /// when breaking ties.
+    const RANKING: &'static [u8];
+    const HAND_SIZE: usize = 5;
+    /// Cards that stand in for whichever card makes the strongest hand.
+    const WILD: &'static [u8] = &[];
+    const TIE_BREAK: TieBreak = TieBreak::InOrder;
+
+    fn card_index(card: u8) -> Option<usize> {
+        Self::RANKING.iter().position(|&ranked| ranked == card)
+    }
+
+    fn is_wild(card: u8) -> bool {
+        Self::WILD.contains(&card)
+    }
+}
+
+/// How two hands of the same type are ordered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TieBreak {
+    /// Card by card in the order they were dealt, as in the puzzle.
+    InOrder,
+    /// Card by card from each hand's highest card down, as in poker. The order the cards
+    /// were dealt in plays no part, so hands holding the same cards compare equal.
+    HighestFirst,
+}
+
+/// The puzzle's part 1 rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Standard;
+
+impl Rules for Standard {
+    const RANKING: &'static [u8] = b"23456789TJQKA";
+}
+
+/// The puzzle's part 2 rules: jokers are wild but the weakest card on their own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JokersWild;
+
+impl Rules for JokersWild {
+    const RANKING: &'static [u8] = b"J23456789TQKA";
+    const WILD: &'static [u8] = b"J";
+}
+
+/// Everything that decides how two hands compare: their type, then their cards in
+/// tie-break order.
+type Key = (HandType, Vec<usize>);
+
+/// `R::HAND_SIZE` cards under rule set `R`, built with `TryFrom<&str>` so every card is
+/// known to the rules and hands are totally ordered. The hand's type and sort key are
+/// worked out once, when the hand is built, so sorting never reclassifies.
+#[derive(Debug, Clone)]
+pub struct Hand<R = Standard> {
+    cards: Vec<u8>,
+    key: Key,
+    rules: PhantomData<R>,
+}
+
+impl<R: Rules> Hand<R> {
+    fn new(cards: Vec<u8>) -> Result<Hand<R>, HandError> {
+        // An unknown card is reported in preference to a miscount, as it is usually why the
+        // count is off.
+        let mut tie_breakers = cards
+            .iter()
+            .enumerate()
+            .map(|(position, &card)| {
+                R::card_index(card).ok_or(HandError::UnknownCard { position, card })
+            })
+            .collect::<Result<Vec<usize>, HandError>>()?;
+        if cards.len() != R::HAND_SIZE {
+            return Err(HandError::Length {
+                expected: R::HAND_SIZE,
+                found: cards.len(),
+            });
+        }
+        if R::TIE_BREAK == TieBreak::HighestFirst {
+            tie_breakers.sort_unstable_by(|a, b| b.cmp(a));
+        }
+        Ok(Hand {
+            key: (classify::<R>(&cards), tie_breakers),
+            cards,
+            rules: PhantomData,
+        })
+    }
+
+    /// The same cards played under different rules, which may not know every card or may
+    /// deal a different number of them.
+    pub fn with_rules<S: Rules>(&self) -> Result<Hand<S>, HandError> {
+        Hand::new(self.cards.clone())
+    }
+
+    pub fn get_type(&self) -> &HandType {
+        &self.key.0
+    }
+}
+
+impl<R: Rules> TryFrom<&str> for Hand<R> {
+    type Error = HandError;
+
+    fn try_from(text: &str) -> Result<Hand<R>, HandError> {
+        Hand::new(text.as_bytes().to_vec())
+    }
+}
+
+impl<R> fmt::Display for Hand<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", String::from_utf8_lossy(&self.cards))
+    }
+}
+
+/// Why a string is not a hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HandError {
+    Length {
+        expected: usize,
+        found: usize,
+    },
+    /// A byte the rules do not rank, at its byte offset in the hand.
+    UnknownCard {
+        position: usize,
+        card: u8,
+    },
+}
+
+impl fmt::Display for HandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HandError::Length { expected, found } => {
+                write!(f, "expected {expected} cards, found {found}")
+            }
+            HandError::UnknownCard { position, card } => write!(
+                f,
+                "unknown card {:?} at position {}",
+                char::from(*card),
+                position + 1
+            ),
+        }
+    }
+}
+
+impl std::error::Error for HandError {}
+
+/// How a hand got its type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    /// Each distinct card that is not wild and how many of it the hand holds, largest group
+    /// (then highest card) first.
+    pub histogram: Vec<(u8, usize)>,
+    pub wild: usize,
+    /// The card every wild card stands in for, when there are any.
+    pub substitute: Option<u8>,
+    pub hand_type: HandType,
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let groups: Vec<String> = self
+            .histogram
+            .iter()
+            .map(|&(card, count)| format!("{}:{count}", char::from(card)))
+            .collect();
+        write!(f, "{}", groups.join(" "))?;
+        if self.wild > 0 {
+            if !groups.is_empty() {
+                write!(f, " ")?;
+            }
+            write!(f, "wild:{}", self.wild)?;
+            if let Some(card) = self.substitute {
+                write!(f, " as {}", char::from(card))?;
+            }
+        }
+        write!(f, " => {}", self.hand_type)
+    }
+}
+
+/// Which comparison put one hand above or below another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decision {
+    Type,
+    /// The 0-based position, in tie-break order, of the first card that differed.
+    Card(usize),
+    Tie,
+}
+
+impl<R: Rules> Hand<R> {
+    pub fn explain(&self) -> Explanation {
+        explain::<R>(&self.cards)
+    }
+
+    /// Where `self` and `other` first differ.
+    pub fn decide(&self, other: &Hand<R>) -> Decision {
+        if self.key.0 != other.key.0 {
+            return Decision::Type;
+        }
+        match (0..R::HAND_SIZE).find(|&i| self.key.1[i] != other.key.1[i]) {
+            Some(position) => Decision::Card(position),
+            None => Decision::Tie,
+        }
+    }
+}
+
+/// Types a hand from the sizes of its groups of matching cards. Whatever the wild cards
+/// stand in for, the best they can do is all join the largest group.
+fn explain<R: Rules>(cards: &[u8]) -> Explanation {
+    let mut counts = vec![0usize; R::RANKING.len()];
+    let mut wild = 0;
+    for &card in cards {
+        if R::is_wild(card) {
+            wild += 1;
+        } else if let Some(index) = R::card_index(card) {
+            counts[index] += 1;
+        }
+    }
+    let mut histogram: Vec<(u8, usize)> = counts
+        .iter()
+        .enumerate()
+        .filter(|&(_, &count)| count > 0)
+        .map(|(index, &count)| (R::RANKING[index], count))
+        .rev()
+        .collect();
+    histogram.sort_by_key(|&(_, count)| Reverse(count));
+
+    let mut groups: Vec<usize> = histogram.iter().map(|&(_, count)| count).collect();
+    match groups.first_mut() {
+        Some(largest) => *largest += wild,
+        None => groups.push(wild),
+    }
+    let substitute = match histogram.first() {
+        _ if wild == 0 => None,
+        Some(&(card, _)) => Some(card),
+        None => R::RANKING
+            .iter()
+            .rev()
+            .copied()
+            .find(|&card| !R::is_wild(card)),
+    };
+    Explanation {
+        histogram,
+        wild,
+        substitute,
+        hand_type: HandType { groups },
+    }
+}
+
+fn classify<R: Rules>(cards: &[u8]) -> HandType {
+    explain::<R>(cards).hand_type
+}
+
+/// Hands are equal when they compare equal, which under `TieBreak::HighestFirst` includes
+/// the same cards dealt in a different order.
+impl<R: Rules> PartialEq for Hand<R> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl<R: Rules> Eq for Hand<R> {}
+
+impl<R: Rules> PartialOrd for Hand<R> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<R: Rules> Ord for Hand<R> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+/// A hand's shape: the sizes of its groups of matching cards, largest first. Comparing
+/// shapes group by group ranks them for any hand size, and for five cards gives the
+/// puzzle's order from high card up to five of a kind.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct HandType {
+    groups: Vec<usize>,
+}
+
+impl HandType {
+    /// The type with the given group sizes, in any order.
+    pub fn new(groups: &[usize]) -> HandType {
+        let mut groups = groups.to_vec();
+        groups.sort_unstable_by(|a, b| b.cmp(a));
+        HandType { groups }
+    }
+}
+
+impl fmt::Display for HandType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self.groups.as_slice() {
+            [5] => "five of a kind",
+            [4, 1] => "four of a kind",
+            [3, 2] => "full house",
+            [3, 1, 1] => "three of a kind",
+            [2, 2, 1] => "two pair",
+            [2, 1, 1, 1] => "one pair",
+            [1, 1, 1, 1, 1] => "high card",
+            groups => {
+                let sizes: Vec<String> = groups.iter().map(usize::to_string).collect();
+                return write!(f, "groups of {}", sizes.join("+"));
+            }
+        };
+        write!(f, "{name}")
+    }
+}
+
+fn parse_line(line_number: usize, line: &str) -> Result<(Hand, usize), ParseError> {
+    let (hand_str, bid_str) = line
+        .split_once(' ')
+        .ok_or_else(|| ParseError::missing(line_number, line, "expected '<hand> <bid>'"))?;
+    let hand = Hand::try_from(hand_str).map_err(|err| {
+        let fragment = match err {
+            HandError::UnknownCard { position, .. } => {
+                let width = hand_str[position..]
+                    .chars()
+                    .next()
+                    .map_or(1, char::len_utf8);
+                &hand_str[position..position + width]
+            }
+            HandError::Length { .. } => hand_str,
+        };
+        ParseError::at(line_number, line, fragment, err.to_string())
+    })?;
+    Ok((hand, parse_number(line_number, line, bid_str.trim())?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+    fn hand<R: Rules>(cards: &str) -> Hand<R> {
+        Hand::try_from(cards).unwrap()
+    }
+
+    #[test]
+    fn example_answers() {
+        let hands = Day7::parse(&EXAMPLE.into()).unwrap();
+        assert_eq!(Day7::part1(&hands), Ok(6440));
+        assert_eq!(Day7::part2(&hands), Ok(5905));
+    }
+
+    #[test]
+    fn jokers_only_hand_is_the_weakest_five_of_a_kind() {
+        let jokers = hand::<JokersWild>("JJJJJ");
+        assert_eq!(jokers.get_type(), &HandType::new(&[5]));
+        assert!(jokers < hand("22222"));
+        assert!(jokers > hand("AAAAK"));
+    }
+
+    #[test]
+    fn jokers_join_the_largest_group() {
+        assert_eq!(
+            hand::<JokersWild>("KTJJT").get_type(),
+            &HandType::new(&[4, 1])
+        );
+        assert_eq!(
+            hand::<JokersWild>("2345J").get_type(),
+            &HandType::new(&[2, 1, 1, 1])
+        );
+        assert_eq!(
+            hand::<Standard>("KTJJT").get_type(),
+            &HandType::new(&[2, 2, 1])
+        );
+    }
+
+    #[test]
+    fn jokers_match_trying_every_substitution() {
+        let deck = b"2345JA";
+        for n in 0..deck.len().pow(5) {
+            let mut cards = vec![0; 5];
+            for (i, card) in cards.iter_mut().enumerate() {
+                *card = deck[n / deck.len().pow(i as u32) % deck.len()];
+            }
+            let best = b"23456789TQKA"
+                .iter()
+                .map(|&substitute| {
+                    let substituted: Vec<u8> = cards
+                        .iter()
+                        .map(|&c| if c == b'J' { substitute } else { c })
+                        .collect();
+                    classify::<Standard>(&substituted)
+                })
+                .max();
+            assert_eq!(Some(classify::<JokersWild>(&cards)), best, "{cards:?}");
+        }
+    }
+
+    #[test]
+    fn invalid_hands_are_rejected() {
+        assert_eq!(
+            Hand::<Standard>::try_from("AK1QT"),
+            Err(HandError::UnknownCard {
+                position: 2,
+                card: b'1'
+            })
+        );
+        assert_eq!(
+            Hand::<Standard>::try_from("AKQT"),
+            Err(HandError::Length {
+                expected: 5,
+                found: 4
+            })
+        );
+        assert_eq!(
+            Hand::<Standard>::try_from("AKQTxx"),
+            Err(HandError::UnknownCard {
+                position: 4,
+                card: b'x'
+            })
+        );
+
+        let err = Day7::parse(&"32T3K 765\nKKé77 28".into()).err().unwrap();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "é"));
+        let err = Day7::parse(&"32T3 765".into()).err().unwrap();
+        assert_eq!(
+            (err.column, err.message.as_str()),
+            (1, "expected 5 cards, found 4")
+        );
+        let err = Day7::parse(&"32T3K".into()).err().unwrap();
+        assert_eq!(err.column, 6);
+    }
+
+    #[test]
+    fn ordering_is_total() {
+        assert_total::<JokersWild>();
+        assert_total::<AcesLowPoker>();
+        let (dealt, sorted) = (hand::<AcesLowPoker>("2345K"), hand("K2345"));
+        assert_eq!(dealt.cmp(&sorted), Ordering::Equal);
+        assert_eq!(dealt, sorted);
+    }
+
+    fn assert_total<R: Rules>() {
+        let deck = b"23TJQKA";
+        let hands: Vec<Hand<R>> = (0..300)
+            .map(|n: usize| {
+                let cards: Vec<u8> = (0..5)
+                    .map(|i| deck[(n * 31 + i * n * n) % deck.len()])
+                    .collect();
+                hand(std::str::from_utf8(&cards).unwrap())
+            })
+            .collect();
+        for a in hands.iter() {
+            for b in hands.iter() {
+                assert_eq!(a.cmp(b), b.cmp(a).reverse());
+                assert_eq!(a.cmp(b) == Ordering::Equal, a == b);
+            }
+        }
+    }
+
+    #[test]
+    fn rules_without_a_card_cannot_take_the_hand() {
+        let hand = hand::<Standard>("A2345");
+        assert!(hand.with_rules::<JokersWild>().is_ok());
+        assert_eq!(
+            hand.with_rules::<NoAces>(),
+            Err(HandError::UnknownCard {
+                position: 0,
+                card: b'A'
+            })
+        );
+    }
+
+    #[test]
+    fn explanations_show_the_substitution() {
+        let explanation = hand::<JokersWild>("KTJJT").explain();
+        assert_eq!(explanation.histogram, vec![(b'T', 2), (b'K', 1)]);
+        assert_eq!(explanation.substitute, Some(b'T'));
+        assert_eq!(
+            explanation.to_string(),
+            "T:2 K:1 wild:2 as T => four of a kind"
+        );
+        assert_eq!(
+            hand::<JokersWild>("JJJJJ").explain().to_string(),
+            "wild:5 as A => five of a kind"
+        );
+        assert_eq!(
+            hand::<Standard>("KTJJT").explain().to_string(),
+            "J:2 T:2 K:1 => two pair"
+        );
+    }
+
+    #[test]
+    fn decisions_name_the_deciding_card() {
+        let kk677 = hand::<Standard>("KK677");
+        assert_eq!(kk677.decide(&hand("KTJJT")), Decision::Card(1));
+        assert_eq!(kk677.decide(&hand("32T3K")), Decision::Type);
+        assert_eq!(kk677.decide(&kk677), Decision::Tie);
+    }
+
+    #[test]
+    fn ranking_table_lists_every_hand() {
+        let hands = Day7::parse(&EXAMPLE.into()).unwrap();
+        let table = Day7::explain(&hands, Part::Two).unwrap();
+        assert_eq!(table.len(), 6);
+        assert_eq!(
+            table[1],
+            "    1  32T3K     765        765  -            3:2 K:1 T:1 2:1 => one pair"
+        );
+        assert!(table[5].starts_with("    5  KTJJT     220       1100  on card 1"));
+        let total: usize = table[1..]
+            .iter()
+            .map(|row| {
+                row.split_whitespace()
+                    .nth(3)
+                    .unwrap()
+                    .parse::<usize>()
+                    .unwrap()
+            })
+            .sum();
+        assert_eq!(Ok(total), Day7::part2(&hands));
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct SevenCard;
+
+    impl Rules for SevenCard {
+        const RANKING: &'static [u8] = b"23456789TJQKA";
+        const HAND_SIZE: usize = 7;
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Dice;
+
+    impl Rules for Dice {
+        const RANKING: &'static [u8] = b"123456";
+        const HAND_SIZE: usize = 3;
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Handful;
+
+    impl Rules for Handful {
+        const RANKING: &'static [u8] = b"J23456789TQKA";
+        const HAND_SIZE: usize = 300;
+        const WILD: &'static [u8] = b"J";
+    }
+
+    #[test]
+    fn other_hand_sizes_and_decks() {
+        let two_triples = hand::<SevenCard>("AAAKKK2");
+        assert_eq!(two_triples.get_type(), &HandType::new(&[3, 3, 1]));
+        assert_eq!(two_triples.get_type().to_string(), "groups of 3+3+1");
+        assert!(two_triples < hand("2222345"));
+        assert!(two_triples < hand("2222333"));
+        assert!(two_triples > hand("AAAKK22"));
+        assert_eq!(
+            Hand::<SevenCard>::try_from("AAAKK"),
+            Err(HandError::Length {
+                expected: 7,
+                found: 5
+            })
+        );
+
+        assert!(hand::<Dice>("611") > hand("543"));
+        assert!(hand::<Dice>("611") < hand("622"));
+        assert_eq!(hand::<Dice>("616").get_type().to_string(), "groups of 2+1");
+        assert!(hand::<Standard>("AKQJT").with_rules::<Dice>().is_err());
+
+        let handful = hand::<Handful>(&format!("{}{}", "A".repeat(200), "J".repeat(100)));
+        assert_eq!(handful.explain().histogram, vec![(b'A', 200)]);
+        assert_eq!(handful.explain().wild, 100);
+        assert_eq!(handful.get_type().to_string(), "groups of 300");
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct NoAces;
+
+    impl Rules for NoAces {
+        const RANKING: &'static [u8] = b"23456789TJQK";
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct TwosWild;
+
+    impl Rules for TwosWild {
+        const RANKING: &'static [u8] = b"23456789TJQKA";
+        const WILD: &'static [u8] = b"2";
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct AcesLowPoker;
+
+    impl Rules for AcesLowPoker {
+        const RANKING: &'static [u8] = b"A23456789TJQK";
+        const TIE_BREAK: TieBreak = TieBreak::HighestFirst;
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct JacksAndTensWild;
+
+    impl Rules for JacksAndTensWild {
+        const RANKING: &'static [u8] = b"TJ23456789QKA";
+        const WILD: &'static [u8] = b"TJ";
+    }
+
+    #[test]
+    fn other_rule_sets() {
+        let twos = hand::<TwosWild>("2K2K3");
+        assert_eq!(twos.get_type(), &HandType::new(&[4, 1]));
+        assert!(twos > hand("3KKK3"));
+
+        let aces_low = hand::<AcesLowPoker>("A2345");
+        assert!(aces_low < hand("23456"));
+        // Highest-first ties look past the dealt order: K beats Q whatever comes first.
+        assert!(hand::<AcesLowPoker>("2345K") > hand("Q2346"));
+
+        let wild = hand::<JacksAndTensWild>("TJ9Q9");
+        assert_eq!(wild.get_type(), &HandType::new(&[4, 1]));
+        assert!(wild < hand("99Q99"));
+    }
+}
@@ -0,0 +1,485 @@
+use aoc_core::{Error, ParseError, Part, PuzzleInput, Solution};
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    const EXAMPLES: [&'static str; 2] = [
+        include_str!("../fixtures/example1.txt"),
+        include_str!("../fixtures/example3.txt"),
+    ];
+    type Input = (Vec<Direction>, NodeMap);
+    type Answer = usize;
+
+    fn parse(input: &PuzzleInput) -> Result<(Vec<Direction>, NodeMap), ParseError> {
+        parse(input)
+    }
+
+    fn part1((path, node_map): &(Vec<Direction>, NodeMap)) -> Result<usize, Error> {
+        for label in [START_NODE, END_NODE] {
+            if !node_map.nodes.contains_key(&label) {
+                return Err(Error::Solve(format!(
+                    "no {} node",
+                    String::from_utf8_lossy(&label)
+                )));
+            }
+        }
+        // Each pass starts at path position 0, so a node seen at the start of an earlier
+        // pass repeats a (node, path position) state and the walk will never end.
+        let mut seen = HashSet::new();
+        let mut steps = 0;
+        let mut current = START_NODE.as_ref();
+        while current != END_NODE {
+            if !seen.insert(current) {
+                return Err(Error::Solve(format!(
+                    "{} cannot be reached from {}",
+                    String::from_utf8_lossy(&END_NODE),
+                    String::from_utf8_lossy(&START_NODE)
+                )));
+            }
+            current = node_map.traverse(current, path.as_slice(), &mut steps);
+        }
+        Ok(steps)
+    }
+
+    fn part2((path, node_map): &(Vec<Direction>, NodeMap)) -> Result<usize, Error> {
+        ghost_steps(path, node_map).0
+    }
+
+    fn solve(input: &(Vec<Direction>, NodeMap), part: Part) -> (Result<usize, Error>, Vec<String>) {
+        match part {
+            Part::One => (Day8::part1(input), Vec::new()),
+            Part::Two => ghost_steps(&input.0, &input.1),
+        }
+    }
+}
+
+/// Part 2's answer, with a note listing the ghosts whose cycles needed the general CRT
+/// solution instead of the plain LCM.
+fn ghost_steps(path: &[Direction], node_map: &NodeMap) -> (Result<usize, Error>, Vec<String>) {
+    let cycles = node_map.ghost_cycles(path);
+    if cycles.is_empty() {
+        return (Err(Error::Solve(String::from("no ..A nodes"))), Vec::new());
+    }
+    let mut notes = Vec::new();
+    let steps = if cycles.iter().all(Cycle::is_simple) {
+        Some(cycles.iter().fold(1, |acc, c| lcm(acc, c.length as u64)) as usize)
+    } else {
+        notes.push(String::from(
+            "cycles do not fit the plain LCM structure, part 2 solves with CRT:",
+        ));
+        for c in cycles.iter().filter(|c| !c.is_simple()) {
+            notes.push(format!("  {}", c.describe()));
+        }
+        simultaneous_steps(&cycles)
+    };
+    let steps = steps.ok_or_else(|| Error::Solve(String::from("the ghosts never line up")));
+    (steps, notes)
+}
+
+#[derive(Debug)]
+struct Node {
+    label: [u8; 3],
+    left: [u8; 3],
+    right: [u8; 3],
+}
+
+const START_NODE: [u8; 3] = [b'A', b'A', b'A'];
+const END_NODE: [u8; 3] = [b'Z', b'Z', b'Z'];
+
+#[derive(Debug)]
+pub struct NodeMap {
+    nodes: HashMap<[u8; 3], Node>,
+}
+
+#[derive(Debug)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+impl NodeMap {
+    fn traverse<'a>(
+        &'a self,
+        start: &'a [u8],
+        path: &[Direction],
+        step_counter: &mut usize,
+    ) -> &'a [u8] {
+        if path.is_empty() {
+            return start;
+        }
+        let mut current = self.nodes.get(start).unwrap();
+        for d in path {
+            current = self.nodes.get(self.step(current, d)).unwrap();
+            *step_counter += 1;
+        }
+        &current.label
+    }
+
+    fn step<'a>(&self, node: &'a Node, direction: &Direction) -> &'a [u8; 3] {
+        match direction {
+            Direction::Left => &node.left,
+            Direction::Right => &node.right,
+        }
+    }
+
+    /// The cycle of a ghost starting from each `..A` node, in label order.
+    fn ghost_cycles(&self, path: &[Direction]) -> Vec<Cycle> {
+        let mut starts: Vec<[u8; 3]> = self
+            .nodes
+            .keys()
+            .filter(|label| label[2] == b'A')
+            .copied()
+            .collect();
+        starts.sort();
+        starts
+            .iter()
+            .map(|start| self.find_cycle(*start, path))
+            .collect()
+    }
+
+    /// Walks from `start` one direction at a time until a (node, path position) state
+    /// repeats, recording every step on which the walker stood on a `..Z` node.
+    fn find_cycle(&self, start: [u8; 3], path: &[Direction]) -> Cycle {
+        let mut seen: HashMap<([u8; 3], usize), usize> = HashMap::new();
+        let mut hits = Vec::new();
+        let mut current = self.nodes.get(&start).unwrap();
+        let mut steps = 0;
+        loop {
+            let state = (current.label, steps % path.len());
+            if let Some(&offset) = seen.get(&state) {
+                let (prefix, in_cycle) = hits.iter().partition(|&&hit| hit < offset);
+                return Cycle {
+                    start,
+                    offset,
+                    length: steps - offset,
+                    prefix_hits: prefix,
+                    cycle_hits: in_cycle,
+                };
+            }
+            seen.insert(state, steps);
+            if current.label[2] == b'Z' {
+                hits.push(steps);
+            }
+            current = self
+                .nodes
+                .get(self.step(current, &path[steps % path.len()]))
+                .unwrap();
+            steps += 1;
+        }
+    }
+}
+
+/// The eventually-periodic walk of a single ghost: after `offset` steps it repeats every
+/// `length` steps. Hits are absolute step counts on which the ghost stands on a `..Z` node,
+/// split by whether they happen before the cycle is entered.
+struct Cycle {
+    start: [u8; 3],
+    offset: usize,
+    length: usize,
+    prefix_hits: Vec<usize>,
+    cycle_hits: Vec<usize>,
+}
+
+impl Cycle {
+    fn hits_at(&self, step: usize) -> bool {
+        if step < self.offset {
+            return self.prefix_hits.contains(&step);
+        }
+        let folded = self.offset + (step - self.offset) % self.length;
+        self.cycle_hits.contains(&folded)
+    }
+
+    /// True when the ghost stands on a `..Z` node exactly at every multiple of the cycle
+    /// length, which is the structure that makes the plain LCM of the cycle lengths correct.
+    /// A single hit at a later multiple (say `2 * length`) is not enough, as it would miss
+    /// the first multiple.
+    fn is_simple(&self) -> bool {
+        self.prefix_hits.is_empty() && self.cycle_hits == [self.length]
+    }
+
+    fn describe(&self) -> String {
+        format!(
+            "{}: offset {}, length {}, hits before cycle {:?}, hits in cycle {:?}",
+            String::from_utf8_lossy(&self.start),
+            self.offset,
+            self.length,
+            self.prefix_hits,
+            self.cycle_hits
+        )
+    }
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn lcm(a: u64, b: u64) -> u64 {
+    a / gcd(a, b) * b
+}
+
+fn ext_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, x, y) = ext_gcd(b, a % b);
+        (g, y, x - (a / b) * y)
+    }
+}
+
+/// Merges `t = r1 (mod m1)` and `t = r2 (mod m2)` into a single congruence, allowing
+/// non-coprime moduli. Returns `None` when the two are inconsistent.
+fn crt_merge((r1, m1): (i128, i128), (r2, m2): (i128, i128)) -> Option<(i128, i128)> {
+    let (g, p, _) = ext_gcd(m1, m2);
+    if (r2 - r1) % g != 0 {
+        return None;
+    }
+    let modulus = m1 / g * m2;
+    let k = ((r2 - r1) / g * p).rem_euclid(m2 / g);
+    Some(((r1 + m1 * k).rem_euclid(modulus), modulus))
+}
+
+/// Finds the first step on which every ghost stands on a `..Z` node at once.
+fn simultaneous_steps(cycles: &[Cycle]) -> Option<usize> {
+    let settled = cycles.iter().map(|c| c.offset).max()?;
+
+    // Before every ghost has entered its cycle, the slowest ghost can only be on a
+    // `..Z` node at one of its prefix hits, so those are the only candidates.
+    let slowest = cycles.iter().find(|c| c.offset == settled)?;
+    if let Some(step) = slowest
+        .prefix_hits
+        .iter()
+        .copied()
+        .find(|&step| cycles.iter().all(|c| c.hits_at(step)))
+    {
+        return Some(step);
+    }
+
+    // Past that point every ghost is periodic: try each combination of in-cycle hits.
+    let mut best: Option<usize> = None;
+    let mut choice = vec![0; cycles.len()];
+    if cycles.iter().any(|c| c.cycle_hits.is_empty()) {
+        return None;
+    }
+    loop {
+        let merged =
+            cycles
+                .iter()
+                .zip(choice.iter())
+                .try_fold((0i128, 1i128), |acc, (cycle, &index)| {
+                    let hit = cycle.cycle_hits[index] as i128;
+                    crt_merge(acc, (hit % cycle.length as i128, cycle.length as i128))
+                });
+        if let Some((residue, modulus)) = merged {
+            let settled = settled as i128;
+            let mut step = residue;
+            if step < settled {
+                step += (settled - step + modulus - 1) / modulus * modulus;
+            }
+            let step = step as usize;
+            best = Some(best.map_or(step, |b| b.min(step)));
+        }
+
+        let mut position = 0;
+        loop {
+            if position == choice.len() {
+                return best;
+            }
+            choice[position] += 1;
+            if choice[position] < cycles[position].cycle_hits.len() {
+                break;
+            }
+            choice[position] = 0;
+            position += 1;
+        }
+    }
+}
+
+fn node_label(text: &str) -> [u8; 3] {
+    let bytes = text.as_bytes();
+    [bytes[0], bytes[1], bytes[2]]
+}
+
+fn parse(input: &PuzzleInput) -> Result<(Vec<Direction>, NodeMap), ParseError> {
+    let sections = input.sections();
+    let mut sections = sections
+        .iter()
+        .map(|section| section.lines().collect::<Vec<_>>());
+    let directions = sections.next().unwrap_or_default();
+    let first = *directions
+        .first()
+        .ok_or_else(|| ParseError::new(1, 1, "", "missing directions"))?;
+    if let Some(extra) = directions.get(1) {
+        return Err(ParseError::at(
+            extra.number,
+            extra.text,
+            extra.text,
+            "expected a blank line after the directions",
+        ));
+    }
+    let path: Vec<Direction> = first
+        .text
+        .char_indices()
+        .map(|(index, c)| match c {
+            'R' => Ok(Direction::Right),
+            'L' => Ok(Direction::Left),
+            _ => Err(ParseError::at(
+                first.number,
+                first.text,
+                &first.text[index..index + c.len_utf8()],
+                "expected 'L' or 'R'",
+            )),
+        })
+        .collect::<Result<_, _>>()?;
+    let nodes = sections.next().unwrap_or_default();
+    if let Some(extra) = sections.next() {
+        return Err(ParseError::at(
+            extra[0].number,
+            extra[0].text,
+            extra[0].text,
+            "expected no more sections after the nodes",
+        ));
+    }
+
+    let node_pattern = Regex::new(r"^([0-9A-Z]{3}) = \(([0-9A-Z]{3}), ([0-9A-Z]{3})\)$").unwrap();
+    let mut node_map = NodeMap {
+        nodes: HashMap::new(),
+    };
+    for line in nodes.iter() {
+        let capture = node_pattern.captures(line.text).ok_or_else(|| {
+            ParseError::at(
+                line.number,
+                line.text,
+                line.text,
+                "expected 'AAA = (BBB, CCC)'",
+            )
+        })?;
+        let (_, [label_str, left_str, right_str]) = capture.extract();
+        let (label, left, right) = (
+            node_label(label_str),
+            node_label(left_str),
+            node_label(right_str),
+        );
+        if node_map.nodes.contains_key(&label) {
+            return Err(ParseError::at(
+                line.number,
+                line.text,
+                label_str,
+                "duplicate node",
+            ));
+        }
+        node_map.nodes.insert(label, Node { label, left, right });
+    }
+
+    for line in nodes.iter() {
+        for target in [&line.text[7..10], &line.text[12..15]] {
+            if !node_map.nodes.contains_key(&node_label(target)) {
+                return Err(ParseError::at(
+                    line.number,
+                    line.text,
+                    target,
+                    "unknown node",
+                ));
+            }
+        }
+    }
+    Ok((path, node_map))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = include_str!("../fixtures/example1.txt");
+    const EXAMPLE2: &str = include_str!("../fixtures/example2.txt");
+    const EXAMPLE3: &str = include_str!("../fixtures/example3.txt");
+
+    #[test]
+    fn example_answers() {
+        assert_eq!(Day8::part1(&Day8::parse(&EXAMPLE1.into()).unwrap()), Ok(2));
+        assert_eq!(Day8::part1(&Day8::parse(&EXAMPLE2.into()).unwrap()), Ok(6));
+        assert_eq!(Day8::part2(&Day8::parse(&EXAMPLE3.into()).unwrap()), Ok(6));
+    }
+
+    /// The first step on which every ghost stands on a `..Z` node, found by walking.
+    fn brute_force(input: &str) -> usize {
+        let (path, node_map) = Day8::parse(&input.into()).unwrap();
+        let mut ghosts: Vec<&[u8; 3]> = node_map
+            .nodes
+            .keys()
+            .filter(|label| label[2] == b'A')
+            .collect();
+        let mut steps = 0;
+        while !ghosts.iter().all(|label| label[2] == b'Z') {
+            let direction = &path[steps % path.len()];
+            for ghost in ghosts.iter_mut() {
+                *ghost = node_map.step(&node_map.nodes[*ghost], direction);
+            }
+            steps += 1;
+        }
+        steps
+    }
+
+    #[test]
+    fn late_single_hit_is_not_simple() {
+        // The ghost's cycle has length 1, but it first reaches `11Z` on step 2.
+        let input = "L\n\n11A = (11B, XXX)\n11B = (11Z, XXX)\n11Z = (11Z, XXX)\nXXX = (XXX, XXX)";
+        let parsed = Day8::parse(&input.into()).unwrap();
+        assert_eq!(Day8::part2(&parsed), Ok(2));
+        assert_eq!(brute_force(input), 2);
+    }
+
+    #[test]
+    fn non_simple_cycles_are_solved_with_crt() {
+        // 11A enters a length-6 cycle after one step and hits `..Z` on steps 3 and 6 of
+        // every lap; 22A enters a length-2 cycle and hits on every even step.
+        let input = "L\n\n\
+            11A = (11B, 11B)\n11B = (11C, 11C)\n11C = (11Z, 11Z)\n11Z = (11D, 11D)\n\
+            11D = (11E, 11E)\n11E = (12Z, 12Z)\n12Z = (11B, 11B)\n\
+            22A = (22B, 22B)\n22B = (22Z, 22Z)\n22Z = (22B, 22B)";
+        let parsed = Day8::parse(&input.into()).unwrap();
+        assert_eq!(brute_force(input), 6);
+        let (steps, notes) = Day8::solve(&parsed, Part::Two);
+        assert_eq!(steps, Ok(6));
+        assert_eq!(
+            notes,
+            [
+                "cycles do not fit the plain LCM structure, part 2 solves with CRT:",
+                "  11A: offset 1, length 6, hits before cycle [], hits in cycle [3, 6]",
+            ]
+        );
+    }
+
+    #[test]
+    fn crt_handles_shared_factors() {
+        assert_eq!(crt_merge((2, 6), (4, 8)), Some((20, 24)));
+        assert_eq!(crt_merge((1, 6), (2, 8)), None);
+    }
+
+    #[test]
+    fn unknown_node_is_reported() {
+        let err = Day8::parse(&"L\n\nAAA = (BBB, AAA)".into()).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 8, "BBB"));
+    }
+
+    #[test]
+    fn missing_or_unreachable_end_is_reported() {
+        let input = "L\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)";
+        let nodes = Day8::parse(&input.into()).unwrap();
+        assert_eq!(
+            Day8::part1(&nodes),
+            Err(Error::Solve(String::from("no ZZZ node")))
+        );
+        let nodes = Day8::parse(&format!("{input}\nZZZ = (ZZZ, ZZZ)").as_str().into()).unwrap();
+        assert_eq!(
+            Day8::part1(&nodes),
+            Err(Error::Solve(String::from("ZZZ cannot be reached from AAA")))
+        );
+    }
+}